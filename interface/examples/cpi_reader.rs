@@ -0,0 +1,43 @@
+//! Example program that reads the supply of a mint account.
+//!
+//! The program only depends on `token-interface` to read the mint state; it
+//! expects the mint account as its first account and writes the supply as a
+//! little-endian `u64` to the return data.
+
+use pinocchio::{
+    account_info::AccountInfo, entrypoint, program::set_return_data, program_error::ProgramError,
+    pubkey::Pubkey, ProgramResult,
+};
+use token_interface::{
+    error::TokenError,
+    state::{load, mint::Mint},
+    TOKEN_PROGRAM_ID,
+};
+
+entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    let [mint_info, _remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // The mint must be owned by the token program, otherwise its data
+    // cannot be trusted.
+    if mint_info.owner() != &TOKEN_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SAFETY: single immutable borrow to `mint_info` account data and
+    // `load` validates that the mint is initialized.
+    let mint = unsafe {
+        load::<Mint>(mint_info.borrow_data_unchecked()).map_err(|_| TokenError::InvalidMint)?
+    };
+
+    set_return_data(&mint.supply().to_le_bytes());
+
+    Ok(())
+}
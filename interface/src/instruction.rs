@@ -21,6 +21,14 @@ pub enum TokenInstruction<'a> {
     ///
     ///   0. `[writable]` The mint to initialize.
     ///   1. `[]` Rent sysvar
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`0`)
+    ///   - `[1]` decimals (`u8`)
+    ///   - `[2..34]` mint authority (`Pubkey`)
+    ///   - `[34]` freeze authority option (`u8`, `0` or `1`)
+    ///   - `[35..67]` freeze authority (`Pubkey`), only when the option is `1`
     InitializeMint {
         /// Number of base 10 digits to the right of the decimal place.
         decimals: u8,
@@ -48,6 +56,10 @@ pub enum TokenInstruction<'a> {
     ///   1. `[]` The mint this account will be associated with.
     ///   2. `[]` The new account's owner/multisignature.
     ///   3. `[]` Rent sysvar
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`1`)
     InitializeAccount,
 
     /// Initializes a multisignature account with N provided signers.
@@ -69,6 +81,11 @@ pub enum TokenInstruction<'a> {
     ///   1. `[]` Rent sysvar
     ///   2. ..2+N. `[]` The signer accounts, must equal to N where 1 <= N <=
     ///      11.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`2`)
+    ///   - `[1]` m (`u8`)
     InitializeMultisig {
         /// The number of signers (M) required to validate this multisignature
         /// account.
@@ -92,6 +109,11 @@ pub enum TokenInstruction<'a> {
     ///   1. `[writable]` The destination account.
     ///   2. `[]` The source account's multisignature owner/delegate.
     ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`3`)
+    ///   - `[1..9]` amount (`u64`, little-endian)
     Transfer {
         /// The amount of tokens to transfer.
         amount: u64,
@@ -112,6 +134,11 @@ pub enum TokenInstruction<'a> {
     ///   1. `[]` The delegate.
     ///   2. `[]` The source account's multisignature owner.
     ///   3. ..3+M `[signer]` M signer accounts
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`4`)
+    ///   - `[1..9]` amount (`u64`, little-endian)
     Approve {
         /// The amount of tokens the delegate is approved for.
         amount: u64,
@@ -129,6 +156,10 @@ pub enum TokenInstruction<'a> {
     ///   0. `[writable]` The source account.
     ///   1. `[]` The source account's multisignature owner.
     ///   2. ..2+M `[signer]` M signer accounts
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`5`)
     Revoke,
 
     /// Sets a new authority of a mint or account.
@@ -143,6 +174,13 @@ pub enum TokenInstruction<'a> {
     ///   0. `[writable]` The mint or account to change the authority of.
     ///   1. `[]` The mint's or account's current multisignature authority.
     ///   2. ..2+M `[signer]` M signer accounts
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`6`)
+    ///   - `[1]` authority type (`u8`)
+    ///   - `[2]` new authority option (`u8`, `0` or `1`)
+    ///   - `[3..35]` new authority (`Pubkey`), only when the option is `1`
     SetAuthority {
         /// The type of authority to update.
         authority_type: AuthorityType,
@@ -165,6 +203,11 @@ pub enum TokenInstruction<'a> {
     ///   1. `[writable]` The account to mint tokens to.
     ///   2. `[]` The mint's multisignature mint-tokens authority.
     ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`7`)
+    ///   - `[1..9]` amount (`u64`, little-endian)
     MintTo {
         /// The amount of new tokens to mint.
         amount: u64,
@@ -185,6 +228,11 @@ pub enum TokenInstruction<'a> {
     ///   1. `[writable]` The token mint.
     ///   2. `[]` The account's multisignature owner/delegate.
     ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`8`)
+    ///   - `[1..9]` amount (`u64`, little-endian)
     Burn {
         /// The amount of tokens to burn.
         amount: u64,
@@ -205,6 +253,10 @@ pub enum TokenInstruction<'a> {
     ///   1. `[writable]` The destination account.
     ///   2. `[]` The account's multisignature owner.
     ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`9`)
     CloseAccount,
 
     /// Freeze an Initialized account using the Mint's freeze_authority (if
@@ -222,6 +274,10 @@ pub enum TokenInstruction<'a> {
     ///   1. `[]` The token mint.
     ///   2. `[]` The mint's multisignature freeze authority.
     ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`10`)
     FreezeAccount,
 
     /// Thaw a Frozen account using the Mint's freeze_authority (if set).
@@ -238,6 +294,10 @@ pub enum TokenInstruction<'a> {
     ///   1. `[]` The token mint.
     ///   2. `[]` The mint's multisignature freeze authority.
     ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`11`)
     ThawAccount,
 
     /// Transfers tokens from one account to another either directly or via a
@@ -263,6 +323,12 @@ pub enum TokenInstruction<'a> {
     ///   2. `[writable]` The destination account.
     ///   3. `[]` The source account's multisignature owner/delegate.
     ///   4. ..4+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`12`)
+    ///   - `[1..9]` amount (`u64`, little-endian)
+    ///   - `[9]` decimals (`u8`)
     TransferChecked {
         /// The amount of tokens to transfer.
         amount: u64,
@@ -291,6 +357,12 @@ pub enum TokenInstruction<'a> {
     ///   2. `[]` The delegate.
     ///   3. `[]` The source account's multisignature owner.
     ///   4. ..4+M `[signer]` M signer accounts
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`13`)
+    ///   - `[1..9]` amount (`u64`, little-endian)
+    ///   - `[9]` decimals (`u8`)
     ApproveChecked {
         /// The amount of tokens the delegate is approved for.
         amount: u64,
@@ -317,6 +389,12 @@ pub enum TokenInstruction<'a> {
     ///   1. `[writable]` The account to mint tokens to.
     ///   2. `[]` The mint's multisignature mint-tokens authority.
     ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`14`)
+    ///   - `[1..9]` amount (`u64`, little-endian)
+    ///   - `[9]` decimals (`u8`)
     MintToChecked {
         /// The amount of new tokens to mint.
         amount: u64,
@@ -344,6 +422,12 @@ pub enum TokenInstruction<'a> {
     ///   1. `[writable]` The token mint.
     ///   2. `[]` The account's multisignature owner/delegate.
     ///   3. ..3+M `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`15`)
+    ///   - `[1..9]` amount (`u64`, little-endian)
+    ///   - `[9]` decimals (`u8`)
     BurnChecked {
         /// The amount of tokens to burn.
         amount: u64,
@@ -361,6 +445,11 @@ pub enum TokenInstruction<'a> {
     ///   0. `[writable]`  The account to initialize.
     ///   1. `[]` The mint this account will be associated with.
     ///   3. `[]` Rent sysvar
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`16`)
+    ///   - `[1..33]` owner (`Pubkey`)
     InitializeAccount2 {
         /// The new account's owner/multisignature.
         owner: Pubkey,
@@ -376,6 +465,10 @@ pub enum TokenInstruction<'a> {
     ///
     ///   0. `[writable]`  The native token account to sync with its underlying
     ///      lamports.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`17`)
     SyncNative,
 
     /// Like InitializeAccount2, but does not require the Rent sysvar to be
@@ -385,6 +478,11 @@ pub enum TokenInstruction<'a> {
     ///
     ///   0. `[writable]`  The account to initialize.
     ///   1. `[]` The mint this account will be associated with.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`18`)
+    ///   - `[1..33]` owner (`Pubkey`)
     InitializeAccount3 {
         /// The new account's owner/multisignature.
         owner: Pubkey,
@@ -398,6 +496,11 @@ pub enum TokenInstruction<'a> {
     ///   0. `[writable]` The multisignature account to initialize.
     ///   1. ..1+N. `[]` The signer accounts, must equal to N where 1 <= N <=
     ///      11.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`19`)
+    ///   - `[1]` m (`u8`)
     InitializeMultisig2 {
         /// The number of signers (M) required to validate this multisignature
         /// account.
@@ -410,6 +513,14 @@ pub enum TokenInstruction<'a> {
     /// Accounts expected by this instruction:
     ///
     ///   0. `[writable]` The mint to initialize.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`20`)
+    ///   - `[1]` decimals (`u8`)
+    ///   - `[2..34]` mint authority (`Pubkey`)
+    ///   - `[34]` freeze authority option (`u8`, `0` or `1`)
+    ///   - `[35..67]` freeze authority (`Pubkey`), only when the option is `1`
     InitializeMint2 {
         /// Number of base 10 digits to the right of the decimal place.
         decimals: u8,
@@ -428,6 +539,10 @@ pub enum TokenInstruction<'a> {
     /// Accounts expected by this instruction:
     ///
     ///   0. `[]` The mint to calculate for
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`21`)
     GetAccountDataSize, // typically, there's also data, but this program ignores it
 
     /// Initialize the Immutable Owner extension for the given token account
@@ -443,7 +558,8 @@ pub enum TokenInstruction<'a> {
     ///   0. `[writable]`  The account to initialize.
     ///
     /// Data expected by this instruction:
-    ///   None
+    ///
+    ///   - `[0]` discriminator (`22`)
     InitializeImmutableOwner,
 
     /// Convert an Amount of tokens to a UiAmount `string`, using the given
@@ -458,6 +574,11 @@ pub enum TokenInstruction<'a> {
     /// Accounts expected by this instruction:
     ///
     ///   0. `[]` The mint to calculate for
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`23`)
+    ///   - `[1..9]` amount (`u64`, little-endian)
     AmountToUiAmount {
         /// The amount of tokens to reformat.
         amount: u64,
@@ -473,6 +594,11 @@ pub enum TokenInstruction<'a> {
     /// Accounts expected by this instruction:
     ///
     ///   0. `[]` The mint to calculate for
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[0]` discriminator (`24`)
+    ///   - `[1..]` ui amount (UTF-8 string)
     UiAmountToAmount {
         /// The ui_amount of tokens to reformat.
         ui_amount: &'a str,
@@ -3,6 +3,55 @@ pub mod instruction;
 pub mod native_mint;
 pub mod state;
 
+/// The address of the Token program, re-exported from [`program::ID`].
+pub use program::ID as TOKEN_PROGRAM_ID;
+
 pub mod program {
-    pinocchio_pubkey::declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    use pinocchio::pubkey::Pubkey;
+
+    /// The const program ID.
+    ///
+    /// It is set at compile time from the `PROGRAM_ID_BASE58` environment variable
+    /// and defaults to the SPL Token program ID
+    /// (`TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`). An invalid value fails the
+    /// build, since it is decoded in a const context.
+    pub const ID: Pubkey = match option_env!("PROGRAM_ID_BASE58") {
+        Some(program_id) => pinocchio_pubkey::from_str(program_id),
+        None => pinocchio_pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    };
+
+    /// Returns `true` if given pubkey is the program ID.
+    #[inline]
+    pub fn check_id(id: &Pubkey) -> bool {
+        id == &ID
+    }
+
+    /// Returns the program ID.
+    #[inline]
+    pub const fn id() -> Pubkey {
+        ID
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::ID;
+
+        /// Bytes of the SPL Token program ID.
+        const SPL_TOKEN_ID: [u8; 32] = [
+            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180,
+            133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
+        ];
+
+        /// Non-default program ID used by CI to check `PROGRAM_ID_BASE58`.
+        const OVERRIDE_ID_BASE58: &str = "11111111111111111111111111111111";
+
+        #[test]
+        fn program_id_matches_environment() {
+            match option_env!("PROGRAM_ID_BASE58") {
+                None => assert_eq!(ID, SPL_TOKEN_ID),
+                Some(OVERRIDE_ID_BASE58) => assert_eq!(ID, [0; 32]),
+                Some(program_id) => panic!("no expected bytes for program ID {program_id}"),
+            }
+        }
+    }
 }
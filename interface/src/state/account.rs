@@ -1,6 +1,8 @@
-use pinocchio::pubkey::Pubkey;
+use core::mem::offset_of;
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 
-use super::{account_state::AccountState, COption, Initializable, RawType};
+use super::{account_state::AccountState, load_unchecked, COption, Initializable, RawType};
+use crate::error::TokenError;
 
 /// Incinerator address.
 const INCINERATOR_ID: Pubkey =
@@ -57,22 +59,17 @@ impl Account {
 
     #[inline(always)]
     pub fn clear_delegate(&mut self) {
-        self.delegate.0[0] = 0;
+        self.delegate.clear();
     }
 
     #[inline(always)]
     pub fn set_delegate(&mut self, delegate: &Pubkey) {
-        self.delegate.0[0] = 1;
-        self.delegate.1 = *delegate;
+        self.delegate.set(*delegate);
     }
 
     #[inline(always)]
     pub fn delegate(&self) -> Option<&Pubkey> {
-        if self.delegate.0[0] == 1 {
-            Some(&self.delegate.1)
-        } else {
-            None
-        }
+        self.delegate.as_ref()
     }
 
     #[inline(always)]
@@ -111,22 +108,17 @@ impl Account {
 
     #[inline(always)]
     pub fn clear_close_authority(&mut self) {
-        self.close_authority.0[0] = 0;
+        self.close_authority.clear();
     }
 
     #[inline(always)]
     pub fn set_close_authority(&mut self, value: &Pubkey) {
-        self.close_authority.0[0] = 1;
-        self.close_authority.1 = *value;
+        self.close_authority.set(*value);
     }
 
     #[inline(always)]
     pub fn close_authority(&self) -> Option<&Pubkey> {
-        if self.close_authority.0[0] == 1 {
-            Some(&self.close_authority.1)
-        } else {
-            None
-        }
+        self.close_authority.as_ref()
     }
 
     #[inline(always)]
@@ -138,6 +130,16 @@ impl Account {
     pub fn is_owned_by_system_program_or_incinerator(&self) -> bool {
         SYSTEM_PROGRAM_ID == self.owner || INCINERATOR_ID == self.owner
     }
+
+    /// Return an error if the account is not initialized.
+    #[inline(always)]
+    pub fn validate_initialized(&self) -> Result<(), TokenError> {
+        if self.is_initialized() {
+            Ok(())
+        } else {
+            Err(TokenError::UninitializedState)
+        }
+    }
 }
 
 impl RawType for Account {
@@ -150,3 +152,69 @@ impl Initializable for Account {
         self.state != AccountState::Uninitialized
     }
 }
+
+impl<'a> TryFrom<&'a [u8]> for &'a Account {
+    type Error = ProgramError;
+
+    /// Return an `Account` reference from the given bytes.
+    ///
+    /// This does not check if the account is initialized.
+    #[inline(always)]
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        // The `state` field is the only field that is not a byte array, so its
+        // value must be validated before the cast.
+        match bytes.get(offset_of!(Account, state)) {
+            Some(state) if *state <= AccountState::Frozen as u8 => (),
+            _ => return Err(ProgramError::InvalidAccountData),
+        }
+        // SAFETY: the length is validated by `load_unchecked` and `state` holds a
+        // valid `AccountState` discriminant; all other fields are byte arrays.
+        unsafe { load_unchecked(bytes) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::load_mut_unchecked;
+
+    #[test]
+    fn validate_initialized() {
+        let mut data = [0u8; Account::LEN];
+        // SAFETY: `data` has the length of an `Account` and `Account` has alignment of 1.
+        let account = unsafe { load_mut_unchecked::<Account>(&mut data).unwrap() };
+
+        assert_eq!(
+            account.validate_initialized(),
+            Err(TokenError::UninitializedState)
+        );
+
+        account.state = AccountState::Initialized;
+
+        assert_eq!(account.validate_initialized(), Ok(()));
+
+        account.state = AccountState::Frozen;
+
+        assert_eq!(account.validate_initialized(), Ok(()));
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        let mut data = [0u8; Account::LEN];
+        assert!(<&Account>::try_from(data.as_slice()).is_ok());
+        assert_eq!(
+            <&Account>::try_from(&data[..Account::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+
+        data[offset_of!(Account, state)] = AccountState::Frozen as u8;
+        let account = <&Account>::try_from(data.as_slice()).unwrap();
+        assert!(account.is_frozen());
+
+        data[offset_of!(Account, state)] = AccountState::Frozen as u8 + 1;
+        assert_eq!(
+            <&Account>::try_from(data.as_slice()).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
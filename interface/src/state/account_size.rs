@@ -0,0 +1,23 @@
+//! Sizes of the account types, as defined by the SPL Token specification.
+//!
+//! These can be used to compute the rent-exempt deposit of an account without
+//! calling `GetAccountDataSize`.
+
+use super::{account::Account, mint::Mint, multisig::Multisig};
+
+/// Size of the `Mint` account data.
+pub const BASE_MINT_SIZE: usize = 82;
+
+/// Size of the `Account` account data.
+pub const BASE_ACCOUNT_SIZE: usize = 165;
+
+/// Size of the `Multisig` account data.
+pub const BASE_MULTISIG_SIZE: usize = 355;
+
+/// Size of the account type discriminant stored after the base account data of
+/// Token-2022 accounts with extensions.
+pub const ACCOUNT_TYPE_SIZE: usize = 1;
+
+const _: () = assert!(BASE_MINT_SIZE == core::mem::size_of::<Mint>());
+const _: () = assert!(BASE_ACCOUNT_SIZE == core::mem::size_of::<Account>());
+const _: () = assert!(BASE_MULTISIG_SIZE == core::mem::size_of::<Multisig>());
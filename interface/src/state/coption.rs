@@ -0,0 +1,126 @@
+/// A C representation of `Option` with the same layout as SPL Token's `COption`.
+///
+/// The first field is a 4-byte discriminant (`[1, 0, 0, 0]` for `Some` and
+/// `[0, 0, 0, 0]` for `None`), followed by the value. The value bytes are
+/// ignored (and zeroed when created from `None`) if the discriminant is `None`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct COption<T>(pub [u8; 4], pub T);
+
+impl<T> COption<T> {
+    /// Returns `true` if the option is a `Some` value.
+    #[inline(always)]
+    pub fn is_some(&self) -> bool {
+        self.0[0] == 1
+    }
+
+    /// Returns an `Option` with a reference to the value.
+    #[inline(always)]
+    pub fn as_ref(&self) -> Option<&T> {
+        if self.is_some() {
+            Some(&self.1)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the option to `Some(value)`.
+    #[inline(always)]
+    pub fn set(&mut self, value: T) {
+        self.0 = [1, 0, 0, 0];
+        self.1 = value;
+    }
+
+    /// Sets the option to `None`, leaving the value bytes untouched.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.0 = [0; 4];
+    }
+}
+
+impl<T: Default> From<Option<T>> for COption<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => COption([1, 0, 0, 0], value),
+            None => COption([0; 4], T::default()),
+        }
+    }
+}
+
+impl<T> From<COption<T>> for Option<T> {
+    fn from(value: COption<T>) -> Self {
+        if value.is_some() {
+            Some(value.1)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for COption<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        mem::{align_of, size_of},
+        slice::from_raw_parts,
+    };
+
+    use pinocchio::pubkey::Pubkey;
+
+    use super::COption;
+
+    fn bytes(value: &COption<Pubkey>) -> &[u8] {
+        // SAFETY: `COption<Pubkey>` only contains byte arrays.
+        unsafe { from_raw_parts(value as *const _ as *const u8, size_of::<COption<Pubkey>>()) }
+    }
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<COption<Pubkey>>(), 36);
+        assert_eq!(align_of::<COption<Pubkey>>(), 1);
+
+        let none = COption::<Pubkey>::from(None);
+        assert_eq!(bytes(&none), [0; 36]);
+
+        let key: Pubkey = [7; 32];
+        let some = COption::from(Some(key));
+
+        let mut expected = [0; 36];
+        expected[0] = 1;
+        expected[4..].copy_from_slice(&key);
+
+        assert_eq!(bytes(&some), expected);
+    }
+
+    #[test]
+    fn conversions() {
+        let key: Pubkey = [7; 32];
+
+        assert_eq!(Option::from(COption::from(Some(key))), Some(key));
+        assert_eq!(Option::<Pubkey>::from(COption::from(None)), None);
+
+        // The value is ignored when the discriminant is `None`.
+        assert_eq!(COption([0; 4], key), COption::from(None));
+        assert_ne!(COption::from(Some(key)), COption::from(None));
+    }
+
+    #[test]
+    fn set_and_clear() {
+        let key: Pubkey = [7; 32];
+
+        let mut option = COption::<Pubkey>::from(None);
+
+        option.set(key);
+        assert_eq!(option.as_ref(), Some(&key));
+        assert_eq!(bytes(&option)[..4], [1, 0, 0, 0]);
+
+        option.clear();
+        assert_eq!(option.as_ref(), None);
+        assert_eq!(bytes(&option)[..4], [0; 4]);
+    }
+}
@@ -0,0 +1,237 @@
+//! Token-2022 extensions.
+//!
+//! Extensions are stored as TLV (type-length-value) records after the base
+//! account data, where each record has a 2-byte type, a 2-byte length and the
+//! extension data.
+
+use pinocchio::program_error::ProgramError;
+
+/// Length of the TLV header (type and length).
+pub const TLV_HEADER_LEN: usize = 4;
+
+/// Extension types.
+///
+/// The discriminants match the values used by Token-2022 on the wire.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExtensionType {
+    /// Used as padding if the account size would otherwise be 355, same as a
+    /// multisig.
+    Uninitialized = 0,
+    /// Includes transfer fee rate info and accompanying authorities to withdraw
+    /// and set the fee.
+    TransferFeeConfig = 1,
+    /// Includes withheld transfer fees.
+    TransferFeeAmount = 2,
+    /// Includes an optional mint close authority.
+    MintCloseAuthority = 3,
+    /// Auditor configuration for confidential transfers.
+    ConfidentialTransferMint = 4,
+    /// State for confidential transfers.
+    ConfidentialTransferAccount = 5,
+    /// Specifies the default `AccountState` for new accounts.
+    DefaultAccountState = 6,
+    /// Indicates that the account owner authority cannot be changed.
+    ImmutableOwner = 7,
+    /// Require inbound transfers to have memo.
+    MemoTransfer = 8,
+    /// Indicates that the tokens from this mint can't be transferred.
+    NonTransferable = 9,
+    /// Tokens accrue interest over time.
+    InterestBearingConfig = 10,
+    /// Locks privileged token operations from happening via CPI.
+    CpiGuard = 11,
+    /// Includes an optional permanent delegate.
+    PermanentDelegate = 12,
+    /// Indicates that the tokens in this account belong to a non-transferable
+    /// mint.
+    NonTransferableAccount = 13,
+    /// Mint requires a CPI to a program implementing the transfer hook interface.
+    TransferHook = 14,
+    /// Indicates that the tokens in this account belong to a mint with a
+    /// transfer hook.
+    TransferHookAccount = 15,
+    /// Includes encrypted withheld fees and the encryption public key that they
+    /// are encrypted under.
+    ConfidentialTransferFeeConfig = 16,
+    /// Includes confidential withheld transfer fees.
+    ConfidentialTransferFeeAmount = 17,
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds metadata.
+    MetadataPointer = 18,
+    /// Mint contains token-metadata.
+    TokenMetadata = 19,
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds group configurations.
+    GroupPointer = 20,
+    /// Mint contains token group configurations.
+    TokenGroup = 21,
+    /// Mint contains a pointer to another account (or the same account) that
+    /// holds group member configurations.
+    GroupMemberPointer = 22,
+    /// Mint contains token group member configurations.
+    TokenGroupMember = 23,
+}
+
+impl TryFrom<u16> for ExtensionType {
+    type Error = ProgramError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ExtensionType::Uninitialized),
+            1 => Ok(ExtensionType::TransferFeeConfig),
+            2 => Ok(ExtensionType::TransferFeeAmount),
+            3 => Ok(ExtensionType::MintCloseAuthority),
+            4 => Ok(ExtensionType::ConfidentialTransferMint),
+            5 => Ok(ExtensionType::ConfidentialTransferAccount),
+            6 => Ok(ExtensionType::DefaultAccountState),
+            7 => Ok(ExtensionType::ImmutableOwner),
+            8 => Ok(ExtensionType::MemoTransfer),
+            9 => Ok(ExtensionType::NonTransferable),
+            10 => Ok(ExtensionType::InterestBearingConfig),
+            11 => Ok(ExtensionType::CpiGuard),
+            12 => Ok(ExtensionType::PermanentDelegate),
+            13 => Ok(ExtensionType::NonTransferableAccount),
+            14 => Ok(ExtensionType::TransferHook),
+            15 => Ok(ExtensionType::TransferHookAccount),
+            16 => Ok(ExtensionType::ConfidentialTransferFeeConfig),
+            17 => Ok(ExtensionType::ConfidentialTransferFeeAmount),
+            18 => Ok(ExtensionType::MetadataPointer),
+            19 => Ok(ExtensionType::TokenMetadata),
+            20 => Ok(ExtensionType::GroupPointer),
+            21 => Ok(ExtensionType::TokenGroup),
+            22 => Ok(ExtensionType::GroupMemberPointer),
+            23 => Ok(ExtensionType::TokenGroupMember),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Iterator over the TLV records of a byte slice.
+///
+/// Each record yields the extension type and its data. The iteration ends when
+/// the remaining bytes cannot hold a TLV header or when an `Uninitialized`
+/// record is found, since the remaining bytes are padding.
+pub struct TlvIterator<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> TlvIterator<'a> {
+    /// Creates an iterator over the TLV records in `data`.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+}
+
+impl<'a> Iterator for TlvIterator<'a> {
+    type Item = Result<(ExtensionType, &'a [u8]), ProgramError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() < TLV_HEADER_LEN {
+            return None;
+        }
+
+        let extension_type = u16::from_le_bytes([self.data[0], self.data[1]]);
+
+        if extension_type == ExtensionType::Uninitialized as u16 {
+            return None;
+        }
+
+        let length = u16::from_le_bytes([self.data[2], self.data[3]]) as usize;
+        let end = TLV_HEADER_LEN + length;
+
+        let result = match (
+            ExtensionType::try_from(extension_type),
+            self.data.get(TLV_HEADER_LEN..end),
+        ) {
+            (Ok(extension_type), Some(value)) => {
+                self.data = &self.data[end..];
+                Ok((extension_type, value))
+            }
+            (Err(error), _) => Err(error),
+            (_, None) => Err(ProgramError::InvalidAccountData),
+        };
+
+        if result.is_err() {
+            // Stops the iteration on malformed data.
+            self.data = &[];
+        }
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExtensionType, TlvIterator};
+
+    /// Encodes a TLV record.
+    fn record(extension_type: u16, value: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&extension_type.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(value);
+        data
+    }
+
+    #[test]
+    fn extension_type_try_from() {
+        assert_eq!(
+            ExtensionType::try_from(3).unwrap(),
+            ExtensionType::MintCloseAuthority
+        );
+        assert_eq!(
+            ExtensionType::try_from(23).unwrap(),
+            ExtensionType::TokenGroupMember
+        );
+        assert!(ExtensionType::try_from(24).is_err());
+    }
+
+    #[test]
+    fn extension_type_round_trip() {
+        for value in 0..=ExtensionType::TokenGroupMember as u16 {
+            let extension_type = ExtensionType::try_from(value).unwrap();
+            assert_eq!(extension_type as u16, value);
+        }
+    }
+
+    #[test]
+    fn tlv_iterator() {
+        let mut data = record(ExtensionType::MintCloseAuthority as u16, &[1; 32]);
+        data.extend(record(ExtensionType::ImmutableOwner as u16, &[]));
+        data.extend(record(ExtensionType::DefaultAccountState as u16, &[2]));
+        // Padding.
+        data.extend([0; 6]);
+
+        let records = TlvIterator::new(&data)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            records,
+            [
+                (ExtensionType::MintCloseAuthority, [1; 32].as_slice()),
+                (ExtensionType::ImmutableOwner, [].as_slice()),
+                (ExtensionType::DefaultAccountState, [2].as_slice()),
+            ]
+        );
+    }
+
+    #[test]
+    fn tlv_iterator_malformed() {
+        // Truncated value.
+        let mut data = record(ExtensionType::MintCloseAuthority as u16, &[1; 32]);
+        data.truncate(20);
+
+        let mut iterator = TlvIterator::new(&data);
+        assert!(iterator.next().unwrap().is_err());
+        assert!(iterator.next().is_none());
+
+        // Unknown extension type.
+        let data = record(u16::MAX, &[1]);
+
+        let mut iterator = TlvIterator::new(&data);
+        assert!(iterator.next().unwrap().is_err());
+        assert!(iterator.next().is_none());
+    }
+}
@@ -1,6 +1,7 @@
-use pinocchio::pubkey::Pubkey;
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 
-use super::{COption, Initializable, RawType};
+use super::{load_unchecked, COption, Initializable, RawType};
+use crate::error::TokenError;
 
 /// Internal representation of a mint data.
 #[repr(C)]
@@ -44,41 +45,41 @@ impl Mint {
 
     #[inline(always)]
     pub fn clear_mint_authority(&mut self) {
-        self.mint_authority.0[0] = 0;
+        self.mint_authority.clear();
     }
 
     #[inline(always)]
     pub fn set_mint_authority(&mut self, mint_authority: &Pubkey) {
-        self.mint_authority.0[0] = 1;
-        self.mint_authority.1 = *mint_authority;
+        self.mint_authority.set(*mint_authority);
     }
 
     #[inline(always)]
     pub fn mint_authority(&self) -> Option<&Pubkey> {
-        if self.mint_authority.0[0] == 1 {
-            Some(&self.mint_authority.1)
-        } else {
-            None
-        }
+        self.mint_authority.as_ref()
     }
 
     #[inline(always)]
     pub fn clear_freeze_authority(&mut self) {
-        self.freeze_authority.0[0] = 0;
+        self.freeze_authority.clear();
     }
 
     #[inline(always)]
     pub fn set_freeze_authority(&mut self, freeze_authority: &Pubkey) {
-        self.freeze_authority.0[0] = 1;
-        self.freeze_authority.1 = *freeze_authority;
+        self.freeze_authority.set(*freeze_authority);
     }
 
     #[inline(always)]
     pub fn freeze_authority(&self) -> Option<&Pubkey> {
-        if self.freeze_authority.0[0] == 1 {
-            Some(&self.freeze_authority.1)
+        self.freeze_authority.as_ref()
+    }
+
+    /// Return an error if the mint is not initialized.
+    #[inline(always)]
+    pub fn validate_initialized(&self) -> Result<(), TokenError> {
+        if self.is_initialized() {
+            Ok(())
         } else {
-            None
+            Err(TokenError::UninitializedState)
         }
     }
 }
@@ -94,3 +95,51 @@ impl Initializable for Mint {
         self.is_initialized == 1
     }
 }
+
+impl<'a> TryFrom<&'a [u8]> for &'a Mint {
+    type Error = ProgramError;
+
+    /// Return a `Mint` reference from the given bytes.
+    ///
+    /// This does not check if the mint is initialized.
+    #[inline(always)]
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        // SAFETY: `Mint` only contains byte fields, so any sequence of bytes with
+        // the expected length is a valid representation of it.
+        unsafe { load_unchecked(bytes) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::load_mut_unchecked;
+
+    #[test]
+    fn validate_initialized() {
+        let mut data = [0u8; Mint::LEN];
+        // SAFETY: `data` has the length of a `Mint` and `Mint` has alignment of 1.
+        let mint = unsafe { load_mut_unchecked::<Mint>(&mut data).unwrap() };
+
+        assert_eq!(
+            mint.validate_initialized(),
+            Err(TokenError::UninitializedState)
+        );
+
+        mint.set_initialized(true);
+
+        assert_eq!(mint.validate_initialized(), Ok(()));
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        let data = [0u8; Mint::LEN];
+        let mint = <&Mint>::try_from(data.as_slice()).unwrap();
+        assert!(!mint.is_initialized());
+
+        assert_eq!(
+            <&Mint>::try_from(&data[..Mint::LEN - 1]).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+}
@@ -1,12 +1,16 @@
+use core::mem::align_of;
+
 use pinocchio::program_error::ProgramError;
 
 pub mod account;
+pub mod account_size;
 pub mod account_state;
+pub mod coption;
+pub mod extensions;
 pub mod mint;
 pub mod multisig;
 
-/// Type alias for fields represented as `COption`.
-pub type COption<T> = ([u8; 4], T);
+pub use coption::COption;
 
 /// Marker trait for types that can cast from a raw pointer.
 ///
@@ -29,7 +33,9 @@ pub trait Initializable {
 ///
 /// # Safety
 ///
-/// The caller must ensure that `bytes` contains a valid representation of `T`.
+/// The caller must ensure that `bytes` contains a valid representation of `T`
+/// and that there are no mutable borrows of `bytes` while the returned reference
+/// is alive. The returned reference has the same lifetime as `bytes`.
 #[inline(always)]
 pub unsafe fn load<T: Initializable + RawType>(bytes: &[u8]) -> Result<&T, ProgramError> {
     load_unchecked(bytes).and_then(|t: &T| {
@@ -48,12 +54,16 @@ pub unsafe fn load<T: Initializable + RawType>(bytes: &[u8]) -> Result<&T, Progr
 ///
 /// # Safety
 ///
-/// The caller must ensure that `bytes` contains a valid representation of `T`.
+/// The caller must ensure that `bytes` contains a valid representation of `T`
+/// and that there are no mutable borrows of `bytes` while the returned reference
+/// is alive. The returned reference has the same lifetime as `bytes`.
 #[inline(always)]
 pub unsafe fn load_unchecked<T: RawType>(bytes: &[u8]) -> Result<&T, ProgramError> {
-    if bytes.len() != T::LEN {
+    if bytes.len() != T::LEN || !is_aligned::<T>(bytes.as_ptr()) {
         return Err(ProgramError::InvalidAccountData);
     }
+    // SAFETY: the length and alignment of `bytes` were validated above and the
+    // reference is tied to the lifetime of `bytes`.
     Ok(&*(bytes.as_ptr() as *const T))
 }
 
@@ -61,7 +71,9 @@ pub unsafe fn load_unchecked<T: RawType>(bytes: &[u8]) -> Result<&T, ProgramErro
 ///
 /// # Safety
 ///
-/// The caller must ensure that `bytes` contains a valid representation of `T`.
+/// The caller must ensure that `bytes` contains a valid representation of `T`
+/// and that there are no other borrows of `bytes` while the returned reference
+/// is alive. The returned reference has the same lifetime as `bytes`.
 #[inline(always)]
 pub unsafe fn load_mut<T: Initializable + RawType>(
     bytes: &mut [u8],
@@ -82,11 +94,80 @@ pub unsafe fn load_mut<T: Initializable + RawType>(
 ///
 /// # Safety
 ///
-/// The caller must ensure that `bytes` contains a valid representation of `T`.
+/// The caller must ensure that `bytes` contains a valid representation of `T`
+/// and that there are no other borrows of `bytes` while the returned reference
+/// is alive. The returned reference has the same lifetime as `bytes`.
 #[inline(always)]
 pub unsafe fn load_mut_unchecked<T: RawType>(bytes: &mut [u8]) -> Result<&mut T, ProgramError> {
-    if bytes.len() != T::LEN {
+    if bytes.len() != T::LEN || !is_aligned::<T>(bytes.as_ptr()) {
         return Err(ProgramError::InvalidAccountData);
     }
+    // SAFETY: the length and alignment of `bytes` were validated above and the
+    // exclusive reference is tied to the lifetime of `bytes`.
     Ok(&mut *(bytes.as_mut_ptr() as *mut T))
 }
+
+/// Checks whether `ptr` is suitably aligned for a `T`.
+///
+/// The runtime guarantees that account data is 8-byte aligned on SBF and the
+/// state types only have byte array fields (alignment of `1`), so this check
+/// is optimized away for them. It is kept defensively in case a type with a
+/// larger alignment implements [`RawType`].
+#[inline(always)]
+fn is_aligned<T>(ptr: *const u8) -> bool {
+    align_of::<T>() == 1 || (ptr as usize) % align_of::<T>() == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::program_error::ProgramError;
+
+    use super::{load, load_mut, load_mut_unchecked, load_unchecked, mint::Mint, RawType};
+
+    /// Type with an alignment requirement larger than `1`.
+    #[repr(C, align(8))]
+    struct Aligned([u8; 8]);
+
+    impl RawType for Aligned {
+        const LEN: usize = 8;
+    }
+
+    #[test]
+    fn load_unchecked_rejects_misaligned_bytes() {
+        let mut buffer = [0u64; 2];
+        // SAFETY: `buffer` is 16 bytes long and `u8` has no alignment requirement.
+        let bytes = unsafe { core::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, 16) };
+
+        // SAFETY: any 8 bytes are a valid `Aligned` and `bytes` is not borrowed
+        // mutably while the references are alive.
+        assert!(unsafe { load_unchecked::<Aligned>(&bytes[0..8]) }.is_ok());
+        assert!(unsafe { load_unchecked::<Aligned>(&bytes[1..9]) }.is_err());
+        assert!(unsafe { load_mut_unchecked::<Aligned>(&mut bytes[1..9]) }.is_err());
+    }
+
+    #[test]
+    fn load_rejects_short_bytes() {
+        let mut bytes = [0u8; Mint::LEN - 1];
+
+        // SAFETY: `load` and `load_mut` validate the length before casting and
+        // `bytes` is not borrowed while the references are alive.
+        unsafe {
+            assert_eq!(
+                load::<Mint>(&[]).err(),
+                Some(ProgramError::InvalidAccountData)
+            );
+            assert_eq!(
+                load::<Mint>(&bytes).err(),
+                Some(ProgramError::InvalidAccountData)
+            );
+            assert_eq!(
+                load_mut::<Mint>(&mut []).err(),
+                Some(ProgramError::InvalidAccountData)
+            );
+            assert_eq!(
+                load_mut::<Mint>(&mut bytes).err(),
+                Some(ProgramError::InvalidAccountData)
+            );
+        }
+    }
+}
@@ -1,6 +1,6 @@
-use pinocchio::pubkey::Pubkey;
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 
-use super::{Initializable, RawType};
+use super::{load_unchecked, Initializable, RawType};
 
 /// Minimum number of multisignature signers (min N)
 pub const MIN_SIGNERS: usize = 1;
@@ -34,6 +34,13 @@ impl Multisig {
     pub fn set_initialized(&mut self, value: bool) {
         self.is_initialized = value as u8;
     }
+
+    /// Returns an iterator over the valid signer public keys, i.e., the first
+    /// `n` keys of [`Multisig::signers`].
+    #[inline(always)]
+    pub fn required_signers_iter(&self) -> impl Iterator<Item = &Pubkey> {
+        self.signers.iter().take(self.n as usize)
+    }
 }
 
 impl RawType for Multisig {
@@ -47,3 +54,47 @@ impl Initializable for Multisig {
         self.is_initialized == 1
     }
 }
+
+impl<'a> TryFrom<&'a [u8]> for &'a Multisig {
+    type Error = ProgramError;
+
+    /// Return a `Multisig` reference from the given bytes.
+    ///
+    /// This does not check if the multisig is initialized.
+    #[inline(always)]
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        // SAFETY: `Multisig` only contains byte fields, so any sequence of bytes with
+        // the expected length is a valid representation of it.
+        unsafe { load_unchecked(bytes) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Multisig, MAX_SIGNERS};
+    use crate::state::RawType;
+
+    fn multisig_bytes(n: u8) -> [u8; Multisig::LEN] {
+        let mut bytes = [0u8; Multisig::LEN];
+        bytes[1] = n;
+        // Fill all signer slots so the iterator must stop at `n`.
+        for (index, key) in bytes[3..].chunks_exact_mut(32).enumerate() {
+            key.fill(index as u8 + 1);
+        }
+        bytes
+    }
+
+    #[test]
+    fn required_signers_iter_stops_at_n() {
+        for n in [0, 3, MAX_SIGNERS as u8] {
+            let bytes = multisig_bytes(n);
+            let multisig: &Multisig = bytes.as_slice().try_into().unwrap();
+
+            assert_eq!(multisig.required_signers_iter().count(), n as usize);
+
+            for (index, key) in multisig.required_signers_iter().enumerate() {
+                assert_eq!(key, &[index as u8 + 1; 32]);
+            }
+        }
+    }
+}
@@ -7,6 +7,10 @@ use crate::processor::*;
 
 program_entrypoint!(process_instruction);
 // Do not allocate memory.
+//
+// The global allocator set by `no_allocator!` panics on any allocation. Since the
+// tests run against the SBF binary, any processing path that allocates (including
+// through a dependency) fails the test suite.
 no_allocator!();
 // Use the default panic handler.
 default_panic_handler!();
@@ -28,16 +32,27 @@ default_panic_handler!();
 /// - `18`: `InitializeAccount3`
 /// - `20`: `InitializeMint2`
 #[inline(always)]
+#[cfg_attr(not(debug_assertions), allow(unused_variables))]
 pub fn process_instruction(
-    _program_id: &Pubkey,
+    program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let (discriminator, instruction_data) = instruction_data
-        .split_first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
+    // The loader only invokes the program with its own ID, so this check is only
+    // included in debug builds, e.g., for test harnesses that call the processor
+    // directly. `cargo test-sbf` builds in release, so it is covered by the host
+    // unit tests instead.
+    #[cfg(debug_assertions)]
+    if program_id != &token_interface::TOKEN_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Empty instruction data has no discriminator, so `split_u8` fails with
+    // `InvalidInstructionData`; for instructions without data fields (e.g.,
+    // `CloseAccount`), the remaining instruction data is simply empty.
+    let (discriminator, instruction_data) = common::split_u8(instruction_data)?;
 
-    match *discriminator {
+    match discriminator {
         // 0 - InitializeMint
         0 => {
             #[cfg(feature = "logging")]
@@ -45,7 +60,6 @@ pub fn process_instruction(
 
             process_initialize_mint(accounts, instruction_data, true)
         }
-
         // 3 - Transfer
         3 => {
             #[cfg(feature = "logging")]
@@ -81,7 +95,7 @@ pub fn process_instruction(
 
             process_initialize_mint2(accounts, instruction_data)
         }
-        _ => process_remaining_instruction(accounts, instruction_data, *discriminator),
+        _ => process_remaining_instruction(accounts, instruction_data, discriminator),
     }
 }
 
@@ -232,3 +246,19 @@ fn process_remaining_instruction(
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
+
+// The program id check is only compiled in debug builds.
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use pinocchio::program_error::ProgramError;
+
+    use super::process_instruction;
+
+    #[test]
+    fn process_instruction_rejects_other_program_id() {
+        assert_eq!(
+            process_instruction(&[1; 32], &[], &[9]),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+}
@@ -2,5 +2,8 @@
 
 #![no_std]
 
+// The modules are private: the processors are `pub(crate)` and the program is
+// only invoked through the `process_instruction` entrypoint registered by
+// `program_entrypoint!`.
 mod entrypoint;
 mod processor;
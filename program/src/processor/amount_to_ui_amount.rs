@@ -8,18 +8,17 @@ use token_interface::{
     state::{load, mint::Mint},
 };
 
-use super::{check_account_owner, MAX_FORMATTED_DIGITS};
+use super::{check_account_owner, common::split_u64, MAX_FORMATTED_DIGITS};
 
 #[inline(always)]
-pub fn process_amount_to_ui_amount(
+pub(crate) fn process_amount_to_ui_amount(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let amount = u64::from_le_bytes(
-        instruction_data
-            .try_into()
-            .map_err(|_error| ProgramError::InvalidInstructionData)?,
-    );
+    let amount = match split_u64(instruction_data)? {
+        (amount, []) => amount,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
 
     let mint_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
     check_account_owner(mint_info)?;
@@ -1,14 +1,13 @@
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
 
-use super::shared;
+use super::{common::split_u64, shared};
 
 #[inline(always)]
-pub fn process_approve(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
-    let amount = u64::from_le_bytes(
-        instruction_data
-            .try_into()
-            .map_err(|_error| ProgramError::InvalidInstructionData)?,
-    );
+pub(crate) fn process_approve(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    let amount = match split_u64(instruction_data)? {
+        (amount, []) => amount,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
 
     shared::approve::process_approve(accounts, amount, None)
 }
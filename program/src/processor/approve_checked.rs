@@ -1,19 +1,17 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
 
-use super::shared;
+use super::{
+    common::{split_u64, split_u8},
+    shared,
+};
 
 #[inline(always)]
-pub fn process_approve_checked(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
-    let (amount, decimals) = instruction_data.split_at(core::mem::size_of::<u64>());
-    let amount = u64::from_le_bytes(
-        amount
-            .try_into()
-            .map_err(|_error| ProgramError::InvalidInstructionData)?,
-    );
+pub(crate) fn process_approve_checked(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let (amount, remaining) = split_u64(instruction_data)?;
+    let (decimals, _remaining) = split_u8(remaining)?;
 
-    shared::approve::process_approve(
-        accounts,
-        amount,
-        Some(*decimals.first().ok_or(ProgramError::InvalidAccountData)?),
-    )
+    shared::approve::process_approve(accounts, amount, Some(decimals))
 }
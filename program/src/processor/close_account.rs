@@ -6,6 +6,8 @@ use token_interface::{
     state::{account::Account, load},
 };
 
+#[cfg(feature = "strict-writable")]
+use super::check_writable;
 use super::validate_owner;
 
 /// Incinerator (`1nc1nerator11111111111111111111111111111111`) address.
@@ -15,12 +17,18 @@ const INCINERATOR_ID: Pubkey = [
 ];
 
 #[inline(always)]
-pub fn process_close_account(accounts: &[AccountInfo]) -> ProgramResult {
+pub(crate) fn process_close_account(accounts: &[AccountInfo]) -> ProgramResult {
     let [source_account_info, destination_account_info, authority_info, remaining @ ..] = accounts
     else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    #[cfg(feature = "strict-writable")]
+    {
+        check_writable(source_account_info)?;
+        check_writable(destination_account_info)?;
+    }
+
     // Comparing whether the AccountInfo's "point" to the same account or
     // not - this is a faster comparison since it just checks the internal
     // raw pointer.
@@ -32,6 +40,9 @@ pub fn process_close_account(accounts: &[AccountInfo]) -> ProgramResult {
         let source_account =
             unsafe { load::<Account>(source_account_info.borrow_data_unchecked())? };
 
+        // The token amount of a native account mirrors its lamports above the
+        // rent-exempt reserve; the account can be closed with a balance since all
+        // lamports are moved to the destination account.
         if !source_account.is_native() && source_account.amount() != 0 {
             return Err(TokenError::NonNativeHasBalance.into());
         }
@@ -0,0 +1,157 @@
+//! Helpers to parse instruction data.
+//!
+//! Each helper reads a value from the start of the instruction data and returns
+//! it together with the remaining bytes, so fields can be read in sequence.
+
+use core::mem::size_of;
+use pinocchio::{
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+/// Reads a `Pubkey` reference from the start of `data`.
+///
+/// Returns the pubkey and the remaining bytes, or `InvalidInstructionData` if
+/// `data` has fewer than `32` bytes.
+#[inline(always)]
+pub(crate) fn split_pubkey(data: &[u8]) -> Result<(&Pubkey, &[u8]), ProgramError> {
+    if data.len() < PUBKEY_BYTES {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (key, remaining) = data.split_at(PUBKEY_BYTES);
+    // SAFETY: `key` has the length of a `Pubkey`, which is a byte array and
+    // therefore has an alignment of 1.
+    Ok((unsafe { &*(key.as_ptr() as *const Pubkey) }, remaining))
+}
+
+/// Reads a little-endian `u64` from the start of `data`.
+///
+/// Returns the value and the remaining bytes, or `InvalidInstructionData` if
+/// `data` has fewer than `8` bytes.
+#[inline(always)]
+pub(crate) fn split_u64(data: &[u8]) -> Result<(u64, &[u8]), ProgramError> {
+    if data.len() < size_of::<u64>() {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (value, remaining) = data.split_at(size_of::<u64>());
+    let value = value
+        .try_into()
+        .map_err(|_error| ProgramError::InvalidInstructionData)?;
+
+    Ok((u64::from_le_bytes(value), remaining))
+}
+
+/// Reads a `u8` from the start of `data`.
+///
+/// Returns the value and the remaining bytes, or `InvalidInstructionData` if
+/// `data` is empty.
+#[inline(always)]
+pub(crate) fn split_u8(data: &[u8]) -> Result<(u8, &[u8]), ProgramError> {
+    match data.split_first() {
+        Some((value, remaining)) => Ok((*value, remaining)),
+        None => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+/// Reads a `bool` from the start of `data`.
+///
+/// Returns the value and the remaining bytes, or `InvalidInstructionData` if
+/// `data` is empty or the byte is neither `0` nor `1`.
+#[inline(always)]
+pub(crate) fn split_bool(data: &[u8]) -> Result<(bool, &[u8]), ProgramError> {
+    match split_u8(data)? {
+        (0, remaining) => Ok((false, remaining)),
+        (1, remaining) => Ok((true, remaining)),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::program_error::ProgramError;
+
+    use super::{split_bool, split_pubkey, split_u64, split_u8};
+
+    #[test]
+    fn split_pubkey_reads_first_32_bytes() {
+        let mut data = [0u8; 40];
+        data[..32].fill(7);
+        data[32..].fill(9);
+
+        let (key, remaining) = split_pubkey(&data).unwrap();
+
+        assert_eq!(key, &[7; 32]);
+        assert_eq!(remaining, &[9; 8]);
+
+        let (key, remaining) = split_pubkey(&data[..32]).unwrap();
+
+        assert_eq!(key, &[7; 32]);
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn split_pubkey_rejects_short_data() {
+        assert_eq!(
+            split_pubkey(&[0; 31]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn split_u64_reads_little_endian_value() {
+        let mut data = [0u8; 10];
+        data[..8].copy_from_slice(&1_000_000u64.to_le_bytes());
+        data[8..].copy_from_slice(&[6, 1]);
+
+        let (value, remaining) = split_u64(&data).unwrap();
+
+        assert_eq!(value, 1_000_000);
+        // The remaining bytes start at byte 8.
+        assert_eq!(remaining, &data[8..]);
+        assert_eq!(remaining.as_ptr(), data[8..].as_ptr());
+
+        let data = u64::MAX.to_le_bytes();
+        let (value, remaining) = split_u64(&data).unwrap();
+
+        assert_eq!(value, u64::MAX);
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn split_u64_rejects_short_data() {
+        assert_eq!(split_u64(&[]), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(
+            split_u64(&[0; 7]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn split_u8_reads_first_byte() {
+        assert_eq!(split_u8(&[6, 1, 2]), Ok((6, [1, 2].as_slice())));
+        assert_eq!(split_u8(&[255]), Ok((255, [].as_slice())));
+    }
+
+    #[test]
+    fn split_u8_rejects_empty_data() {
+        assert_eq!(split_u8(&[]), Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn split_bool_reads_zero_or_one() {
+        assert_eq!(split_bool(&[0, 7]), Ok((false, [7].as_slice())));
+        assert_eq!(split_bool(&[1]), Ok((true, [].as_slice())));
+    }
+
+    #[test]
+    fn split_bool_rejects_invalid_data() {
+        assert_eq!(split_bool(&[]), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(split_bool(&[2]), Err(ProgramError::InvalidInstructionData));
+        assert_eq!(
+            split_bool(&[255, 0]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+}
@@ -9,7 +9,7 @@ use token_interface::{
 use super::check_account_owner;
 
 #[inline(always)]
-pub fn process_get_account_data_size(accounts: &[AccountInfo]) -> ProgramResult {
+pub(crate) fn process_get_account_data_size(accounts: &[AccountInfo]) -> ProgramResult {
     let [mint_info, _remaning @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
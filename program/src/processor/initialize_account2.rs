@@ -1,24 +1,16 @@
-use pinocchio::{
-    account_info::AccountInfo,
-    program_error::ProgramError,
-    pubkey::{Pubkey, PUBKEY_BYTES},
-    ProgramResult,
-};
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
 
-use super::shared;
+use super::{common::split_pubkey, shared};
 
 #[inline(always)]
-pub fn process_initialize_account2(
+pub(crate) fn process_initialize_account2(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    // SAFETY: validate `instruction_data` length.
-    let owner = unsafe {
-        if instruction_data.len() != PUBKEY_BYTES {
-            return Err(ProgramError::InvalidInstructionData);
-        } else {
-            &*(instruction_data.as_ptr() as *const Pubkey)
-        }
+    // The instruction data must only contain the owner.
+    let owner = match split_pubkey(instruction_data)? {
+        (owner, []) => owner,
+        _ => return Err(ProgramError::InvalidInstructionData),
     };
     shared::initialize_account::process_initialize_account(accounts, Some(owner), true)
 }
@@ -5,12 +5,16 @@ use token_interface::{
 };
 
 #[inline(always)]
-pub fn process_initialize_immutable_owner(accounts: &[AccountInfo]) -> ProgramResult {
+pub(crate) fn process_initialize_immutable_owner(accounts: &[AccountInfo]) -> ProgramResult {
     let token_account_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
 
     // SAFETY: single immutable borrow to `token_account_info` account data.
     let account = unsafe { load_unchecked::<Account>(token_account_info.borrow_data_unchecked())? };
 
+    // SPL Token does not support the immutable owner extension, so the instruction
+    // is a no-op on uninitialized accounts (calling it multiple times succeeds) and
+    // the owner of the account remains mutable. It is only rejected once the account
+    // has been initialized.
     if account.is_initialized() {
         return Err(TokenError::AlreadyInUse.into());
     }
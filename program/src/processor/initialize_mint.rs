@@ -2,7 +2,7 @@ use core::{marker::PhantomData, mem::size_of};
 use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
-    pubkey::Pubkey,
+    pubkey::{Pubkey, PUBKEY_BYTES},
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
@@ -11,8 +11,10 @@ use token_interface::{
     state::{load_mut_unchecked, mint::Mint, Initializable},
 };
 
+use super::common::split_bool;
+
 #[inline(always)]
-pub fn process_initialize_mint(
+pub(crate) fn process_initialize_mint(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
     rent_sysvar_account: bool,
@@ -71,7 +73,7 @@ pub fn process_initialize_mint(
 }
 
 /// Instruction data for the `InitializeMint` instruction.
-pub struct InitializeMint<'a> {
+pub(crate) struct InitializeMint<'a> {
     raw: *const u8,
 
     _data: PhantomData<&'a [u8]>,
@@ -79,13 +81,23 @@ pub struct InitializeMint<'a> {
 
 impl InitializeMint<'_> {
     #[inline]
-    pub fn try_from_bytes(bytes: &[u8]) -> Result<InitializeMint, ProgramError> {
+    pub(crate) fn try_from_bytes(bytes: &[u8]) -> Result<InitializeMint, ProgramError> {
         // The minimum expected size of the instruction data.
         // - decimals (1 byte)
         // - mint_authority (32 bytes)
         // - option + freeze_authority (1 byte + 32 bytes)
-        if bytes.len() < 34 || (bytes[33] == 1 && bytes.len() < 66) {
-            return Err(ProgramError::InvalidInstructionData);
+        //
+        // The presence of the freeze authority is determined by the option byte
+        // only (`0` for `None`, `1` for `Some`), matching SPL Token; a zeroed
+        // pubkey with the option byte set is a valid `Some` authority.
+        let option = bytes
+            .get(33..)
+            .ok_or(ProgramError::InvalidInstructionData)?;
+
+        match split_bool(option)? {
+            (false, _) => (),
+            (true, freeze_authority) if freeze_authority.len() >= PUBKEY_BYTES => (),
+            _ => return Err(ProgramError::InvalidInstructionData),
         }
 
         Ok(InitializeMint {
@@ -95,20 +107,20 @@ impl InitializeMint<'_> {
     }
 
     #[inline]
-    pub fn decimals(&self) -> u8 {
+    pub(crate) fn decimals(&self) -> u8 {
         // SAFETY: the `bytes` length was validated in `try_from_bytes`.
         unsafe { *self.raw }
     }
 
     #[inline]
-    pub fn mint_authority(&self) -> &Pubkey {
+    pub(crate) fn mint_authority(&self) -> &Pubkey {
         // SAFETY: the `bytes` length was validated in `try_from_bytes`.
         unsafe { &*(self.raw.add(1) as *const Pubkey) }
     }
 
     #[inline]
-    pub fn freeze_authority(&self) -> Option<&Pubkey> {
-        // SAFETY: the `bytes` length was validated in `try_from_bytes`.
+    pub(crate) fn freeze_authority(&self) -> Option<&Pubkey> {
+        // SAFETY: the `bytes` length and option byte were validated in `try_from_bytes`.
         unsafe {
             if *self.raw.add(33) == 0 {
                 Option::None
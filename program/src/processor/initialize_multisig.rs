@@ -1,15 +1,13 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
 
-use super::shared;
+use super::{common::split_u8, shared};
 
 #[inline(always)]
-pub fn process_initialize_multisig(
+pub(crate) fn process_initialize_multisig(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let m = instruction_data
-        .first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
+    let (m, _remaining) = split_u8(instruction_data)?;
 
-    shared::initialize_multisig::process_initialize_multisig(accounts, *m, true)
+    shared::initialize_multisig::process_initialize_multisig(accounts, m, true)
 }
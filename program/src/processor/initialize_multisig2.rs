@@ -1,14 +1,12 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+use pinocchio::{account_info::AccountInfo, ProgramResult};
 
-use super::shared;
+use super::{common::split_u8, shared};
 
 #[inline(always)]
-pub fn process_initialize_multisig2(
+pub(crate) fn process_initialize_multisig2(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let m = instruction_data
-        .first()
-        .ok_or(ProgramError::InvalidInstructionData)?;
-    shared::initialize_multisig::process_initialize_multisig(accounts, *m, false)
+    let (m, _remaining) = split_u8(instruction_data)?;
+    shared::initialize_multisig::process_initialize_multisig(accounts, m, false)
 }
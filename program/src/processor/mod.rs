@@ -1,3 +1,19 @@
+//! Instruction processors.
+//!
+//! Account data is accessed through `unsafe` borrows to avoid the cost of the
+//! runtime borrow checks. Each `unsafe` block has a `SAFETY` comment stating
+//! the invariants it relies on, which are usually:
+//!
+//! - *single borrow*: the account data is borrowed once in the scope of the
+//!   reference, so there are no aliasing mutable references; when the same
+//!   account may appear twice in the instruction (e.g., self-transfers), the
+//!   borrows are scoped or the keys are compared first.
+//! - *alignment*: the runtime places account data at 8-byte aligned addresses
+//!   and the state types have an alignment of `1`, so the casts in
+//!   `load`/`load_mut` are always aligned.
+//! - *lifetime*: the references returned are tied to the `AccountInfo` borrow,
+//!   which outlives the processor function; they are not stored beyond it.
+
 use core::{
     cmp::max,
     mem::MaybeUninit,
@@ -10,67 +26,69 @@ use pinocchio::{
 };
 use token_interface::{
     error::TokenError,
-    program::ID as TOKEN_PROGRAM_ID,
     state::{
         load,
         multisig::{Multisig, MAX_SIGNERS},
         RawType,
     },
+    TOKEN_PROGRAM_ID,
 };
 
-pub mod amount_to_ui_amount;
-pub mod approve;
-pub mod approve_checked;
-pub mod burn;
-pub mod burn_checked;
-pub mod close_account;
-pub mod freeze_account;
-pub mod get_account_data_size;
-pub mod initialize_account;
-pub mod initialize_account2;
-pub mod initialize_account3;
-pub mod initialize_immutable_owner;
-pub mod initialize_mint;
-pub mod initialize_mint2;
-pub mod initialize_multisig;
-pub mod initialize_multisig2;
-pub mod mint_to;
-pub mod mint_to_checked;
-pub mod revoke;
-pub mod set_authority;
-pub mod sync_native;
-pub mod thaw_account;
-pub mod transfer;
-pub mod transfer_checked;
-pub mod ui_amount_to_amount;
+pub(crate) mod amount_to_ui_amount;
+pub(crate) mod approve;
+pub(crate) mod approve_checked;
+pub(crate) mod burn;
+pub(crate) mod burn_checked;
+pub(crate) mod close_account;
+// Instruction data parsing helpers.
+pub(crate) mod common;
+pub(crate) mod freeze_account;
+pub(crate) mod get_account_data_size;
+pub(crate) mod initialize_account;
+pub(crate) mod initialize_account2;
+pub(crate) mod initialize_account3;
+pub(crate) mod initialize_immutable_owner;
+pub(crate) mod initialize_mint;
+pub(crate) mod initialize_mint2;
+pub(crate) mod initialize_multisig;
+pub(crate) mod initialize_multisig2;
+pub(crate) mod mint_to;
+pub(crate) mod mint_to_checked;
+pub(crate) mod revoke;
+pub(crate) mod set_authority;
+pub(crate) mod sync_native;
+pub(crate) mod thaw_account;
+pub(crate) mod transfer;
+pub(crate) mod transfer_checked;
+pub(crate) mod ui_amount_to_amount;
 // Shared processors.
-pub mod shared;
-
-pub use amount_to_ui_amount::process_amount_to_ui_amount;
-pub use approve::process_approve;
-pub use approve_checked::process_approve_checked;
-pub use burn::process_burn;
-pub use burn_checked::process_burn_checked;
-pub use close_account::process_close_account;
-pub use freeze_account::process_freeze_account;
-pub use get_account_data_size::process_get_account_data_size;
-pub use initialize_account::process_initialize_account;
-pub use initialize_account2::process_initialize_account2;
-pub use initialize_account3::process_initialize_account3;
-pub use initialize_immutable_owner::process_initialize_immutable_owner;
-pub use initialize_mint::process_initialize_mint;
-pub use initialize_mint2::process_initialize_mint2;
-pub use initialize_multisig::process_initialize_multisig;
-pub use initialize_multisig2::process_initialize_multisig2;
-pub use mint_to::process_mint_to;
-pub use mint_to_checked::process_mint_to_checked;
-pub use revoke::process_revoke;
-pub use set_authority::process_set_authority;
-pub use sync_native::process_sync_native;
-pub use thaw_account::process_thaw_account;
-pub use transfer::process_transfer;
-pub use transfer_checked::process_transfer_checked;
-pub use ui_amount_to_amount::process_ui_amount_to_amount;
+pub(crate) mod shared;
+
+pub(crate) use amount_to_ui_amount::process_amount_to_ui_amount;
+pub(crate) use approve::process_approve;
+pub(crate) use approve_checked::process_approve_checked;
+pub(crate) use burn::process_burn;
+pub(crate) use burn_checked::process_burn_checked;
+pub(crate) use close_account::process_close_account;
+pub(crate) use freeze_account::process_freeze_account;
+pub(crate) use get_account_data_size::process_get_account_data_size;
+pub(crate) use initialize_account::process_initialize_account;
+pub(crate) use initialize_account2::process_initialize_account2;
+pub(crate) use initialize_account3::process_initialize_account3;
+pub(crate) use initialize_immutable_owner::process_initialize_immutable_owner;
+pub(crate) use initialize_mint::process_initialize_mint;
+pub(crate) use initialize_mint2::process_initialize_mint2;
+pub(crate) use initialize_multisig::process_initialize_multisig;
+pub(crate) use initialize_multisig2::process_initialize_multisig2;
+pub(crate) use mint_to::process_mint_to;
+pub(crate) use mint_to_checked::process_mint_to_checked;
+pub(crate) use revoke::process_revoke;
+pub(crate) use set_authority::process_set_authority;
+pub(crate) use sync_native::process_sync_native;
+pub(crate) use thaw_account::process_thaw_account;
+pub(crate) use transfer::process_transfer;
+pub(crate) use transfer_checked::process_transfer_checked;
+pub(crate) use ui_amount_to_amount::process_ui_amount_to_amount;
 
 /// An uninitialized byte.
 const UNINIT_BYTE: MaybeUninit<u8> = MaybeUninit::uninit();
@@ -82,6 +100,23 @@ const UNINIT_BYTE: MaybeUninit<u8> = MaybeUninit::uninit();
 /// and the leading zero.
 const MAX_FORMATTED_DIGITS: usize = u8::MAX as usize + 2;
 
+/// Checks that the account is writable.
+///
+/// The runtime already rejects changes to read-only accounts once the
+/// instruction completes, with `ReadonlyDataModified`/`ReadonlyLamportChange`.
+/// This check moves the failure before any processing and surfaces it as
+/// `InvalidAccountData`, so it is only enabled with the `strict-writable`
+/// feature.
+#[cfg(feature = "strict-writable")]
+#[inline(always)]
+fn check_writable(account_info: &AccountInfo) -> ProgramResult {
+    if account_info.is_writable() {
+        Ok(())
+    } else {
+        Err(ProgramError::InvalidAccountData)
+    }
+}
+
 /// Checks that the account is owned by the expected program.
 #[inline(always)]
 fn check_account_owner(account_info: &AccountInfo) -> ProgramResult {
@@ -117,7 +152,7 @@ fn validate_owner(
         let mut matched = [false; MAX_SIGNERS];
 
         for signer in signers.iter() {
-            for (position, key) in multisig.signers[0..multisig.n as usize].iter().enumerate() {
+            for (position, key) in multisig.required_signers_iter().enumerate() {
                 if key == signer.key() && !matched[position] {
                     if !signer.is_signer() {
                         return Err(ProgramError::MissingRequiredSignature);
@@ -7,7 +7,7 @@ use token_interface::{
 use super::validate_owner;
 
 #[inline(always)]
-pub fn process_revoke(accounts: &[AccountInfo], _instruction_data: &[u8]) -> ProgramResult {
+pub(crate) fn process_revoke(accounts: &[AccountInfo], _instruction_data: &[u8]) -> ProgramResult {
     let [source_account_info, owner_info, remaning @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -1,7 +1,10 @@
 use core::marker::PhantomData;
 
 use pinocchio::{
-    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    ProgramResult,
 };
 use token_interface::{
     error::TokenError,
@@ -9,10 +12,13 @@ use token_interface::{
     state::{account::Account, load_mut, mint::Mint, RawType},
 };
 
-use super::validate_owner;
+use super::{common::split_bool, validate_owner};
 
 #[inline(always)]
-pub fn process_set_authority(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+pub(crate) fn process_set_authority(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
     // Validates the instruction data.
 
     let args = SetAuthority::try_from_bytes(instruction_data)?;
@@ -119,12 +125,18 @@ struct SetAuthority<'a> {
 
 impl SetAuthority<'_> {
     #[inline(always)]
-    pub fn try_from_bytes(bytes: &[u8]) -> Result<SetAuthority, ProgramError> {
+    pub(crate) fn try_from_bytes(bytes: &[u8]) -> Result<SetAuthority, ProgramError> {
         // The minimum expected size of the instruction data.
         // - authority_type (1 byte)
         // - option + new_authority (1 byte + 32 bytes)
-        if bytes.len() < 2 || (bytes[1] == 1 && bytes.len() < 34) {
-            return Err(ProgramError::InvalidInstructionData);
+        //
+        // The option byte must be either `0` (`None`) or `1` (`Some`).
+        let option = bytes.get(1..).ok_or(ProgramError::InvalidInstructionData)?;
+
+        match split_bool(option)? {
+            (false, _) => (),
+            (true, new_authority) if new_authority.len() >= PUBKEY_BYTES => (),
+            _ => return Err(ProgramError::InvalidInstructionData),
         }
 
         Ok(SetAuthority {
@@ -134,14 +146,14 @@ impl SetAuthority<'_> {
     }
 
     #[inline(always)]
-    pub fn authority_type(&self) -> Result<AuthorityType, ProgramError> {
+    pub(crate) fn authority_type(&self) -> Result<AuthorityType, ProgramError> {
         // SAFETY: `bytes` length is validated in `try_from_bytes`.
         unsafe { AuthorityType::from(*self.raw) }
     }
 
     #[inline(always)]
-    pub fn new_authority(&self) -> Option<&Pubkey> {
-        // SAFETY: `bytes` length is validated in `try_from_bytes`.
+    pub(crate) fn new_authority(&self) -> Option<&Pubkey> {
+        // SAFETY: `bytes` length and option byte are validated in `try_from_bytes`.
         unsafe {
             if *self.raw.add(1) == 0 {
                 Option::None
@@ -7,7 +7,7 @@ use token_interface::{
 use crate::processor::validate_owner;
 
 #[inline(always)]
-pub fn process_approve(
+pub(crate) fn process_approve(
     accounts: &[AccountInfo],
     amount: u64,
     expected_decimals: Option<u8>,
@@ -54,6 +54,14 @@ pub fn process_approve(
         return Err(TokenError::AccountFrozen.into());
     }
 
+    // SPL Token allows approving an amount greater than the account balance, since
+    // the delegate still cannot move more tokens than the account holds. When the
+    // `strict-approve` feature is enabled, such approvals are rejected instead.
+    #[cfg(feature = "strict-approve")]
+    if amount > source_account.amount() {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+
     if let Some((mint_info, expected_decimals)) = expected_mint_info {
         if mint_info.key() != &source_account.mint {
             return Err(TokenError::MintMismatch.into());
@@ -4,10 +4,12 @@ use token_interface::{
     state::{account::Account, load_mut, mint::Mint},
 };
 
+#[cfg(feature = "strict-writable")]
+use crate::processor::check_writable;
 use crate::processor::{check_account_owner, validate_owner};
 
 #[inline(always)]
-pub fn process_burn(
+pub(crate) fn process_burn(
     accounts: &[AccountInfo],
     amount: u64,
     expected_decimals: Option<u8>,
@@ -16,6 +18,12 @@ pub fn process_burn(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    #[cfg(feature = "strict-writable")]
+    {
+        check_writable(source_account_info)?;
+        check_writable(mint_info)?;
+    }
+
     // SAFETY: single mutable borrow to `source_account_info` account data and
     // `load_mut` validates that the account is initialized.
     let source_account =
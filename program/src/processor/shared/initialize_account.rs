@@ -17,7 +17,7 @@ use token_interface::{
 use crate::processor::check_account_owner;
 
 #[inline(always)]
-pub fn process_initialize_account(
+pub(crate) fn process_initialize_account(
     accounts: &[AccountInfo],
     owner: Option<&Pubkey>,
     rent_sysvar_account: bool,
@@ -40,6 +40,13 @@ pub fn process_initialize_account(
 
     let new_account_info_data_len = new_account_info.data_len();
 
+    // SPL Token requires the rent sysvar account for `InitializeAccount` and
+    // `InitializeAccount2`. When the `optional-rent-sysvar` feature is enabled, a
+    // missing rent sysvar account is accepted instead and the rent is obtained
+    // through the sysvar syscall, the same way `InitializeAccount3` does.
+    #[cfg(feature = "optional-rent-sysvar")]
+    let rent_sysvar_account = rent_sysvar_account && !remaning.is_empty();
+
     let minimum_balance = if rent_sysvar_account {
         let rent_sysvar_info = remaning.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
         // SAFETY: single immutable borrow to `rent_sysvar_info`; account ID and length are
@@ -10,7 +10,7 @@ use token_interface::{
 };
 
 #[inline(always)]
-pub fn process_initialize_multisig(
+pub(crate) fn process_initialize_multisig(
     accounts: &[AccountInfo],
     m: u8,
     rent_sysvar_account: bool,
@@ -64,6 +64,8 @@ pub fn process_initialize_multisig(
         return Err(TokenError::InvalidNumberOfRequiredSigners.into());
     }
 
+    // The signer keys are the keys of the remaining accounts, in the order they
+    // are provided; the instruction data only contains `m`.
     for (i, signer_info) in remaining.iter().enumerate() {
         multisig.signers[i] = *signer_info.key();
     }
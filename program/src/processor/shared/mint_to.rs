@@ -4,10 +4,12 @@ use token_interface::{
     state::{account::Account, load_mut, mint::Mint},
 };
 
+#[cfg(feature = "strict-writable")]
+use crate::processor::check_writable;
 use crate::processor::{check_account_owner, validate_owner};
 
 #[inline(always)]
-pub fn process_mint_to(
+pub(crate) fn process_mint_to(
     accounts: &[AccountInfo],
     amount: u64,
     expected_decimals: Option<u8>,
@@ -16,6 +18,12 @@ pub fn process_mint_to(
         return Err(ProgramError::NotEnoughAccountKeys);
     };
 
+    #[cfg(feature = "strict-writable")]
+    {
+        check_writable(mint_info)?;
+        check_writable(destination_account_info)?;
+    }
+
     // Validates the destination account.
 
     // SAFETY: single mutable borrow to `destination_account_info` account data and
@@ -3,10 +3,10 @@
 //! This module contains the shared processor functions that are used by
 //! the multiple instruction processors.
 
-pub mod approve;
-pub mod burn;
-pub mod initialize_account;
-pub mod initialize_multisig;
-pub mod mint_to;
-pub mod toggle_account_state;
-pub mod transfer;
+pub(crate) mod approve;
+pub(crate) mod burn;
+pub(crate) mod initialize_account;
+pub(crate) mod initialize_multisig;
+pub(crate) mod mint_to;
+pub(crate) mod toggle_account_state;
+pub(crate) mod transfer;
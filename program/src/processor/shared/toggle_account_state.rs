@@ -7,7 +7,10 @@ use token_interface::{
 use crate::processor::validate_owner;
 
 #[inline(always)]
-pub fn process_toggle_account_state(accounts: &[AccountInfo], freeze: bool) -> ProgramResult {
+pub(crate) fn process_toggle_account_state(
+    accounts: &[AccountInfo],
+    freeze: bool,
+) -> ProgramResult {
     let [source_account_info, mint_info, authority_info, remaining @ ..] = accounts else {
         return Err(ProgramError::NotEnoughAccountKeys);
     };
@@ -4,14 +4,24 @@ use token_interface::{
     state::{account::Account, load, load_mut, load_mut_unchecked, mint::Mint},
 };
 
+#[cfg(feature = "strict-writable")]
+use crate::processor::check_writable;
 use crate::processor::{check_account_owner, validate_owner};
 
 #[inline(always)]
-pub fn process_transfer(
+pub(crate) fn process_transfer(
     accounts: &[AccountInfo],
     amount: u64,
     expected_decimals: Option<u8>,
 ) -> ProgramResult {
+    // Zero-amount transfers are valid in SPL Token and succeed by default. The
+    // `strict-zero-amount` feature rejects them instead, for both `Transfer` and
+    // `TransferChecked`.
+    #[cfg(feature = "strict-zero-amount")]
+    if amount == 0 {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+
     // Accounts expected depend on whether we have the mint `decimals` or not; when we have the
     // mint `decimals`, we expect the mint account to be present.
 
@@ -49,6 +59,12 @@ pub fn process_transfer(
         )
     };
 
+    #[cfg(feature = "strict-writable")]
+    {
+        check_writable(source_account_info)?;
+        check_writable(destination_account_info)?;
+    }
+
     // Validates source and destination accounts.
 
     // SAFETY: single mutable borrow to `source_account_info` account data and
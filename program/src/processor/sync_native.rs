@@ -7,7 +7,7 @@ use token_interface::{
 use super::check_account_owner;
 
 #[inline(always)]
-pub fn process_sync_native(accounts: &[AccountInfo]) -> ProgramResult {
+pub(crate) fn process_sync_native(accounts: &[AccountInfo]) -> ProgramResult {
     let native_account_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
 
     check_account_owner(native_account_info)?;
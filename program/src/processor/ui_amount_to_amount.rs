@@ -10,7 +10,7 @@ use token_interface::{
 use super::{check_account_owner, try_ui_amount_into_amount};
 
 #[inline(always)]
-pub fn process_ui_amount_to_amount(
+pub(crate) fn process_ui_amount_to_amount(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
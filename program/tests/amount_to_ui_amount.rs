@@ -4,7 +4,13 @@ mod setup;
 
 use setup::{mint, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
-use solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+use solana_sdk::{
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
 
 #[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
 #[tokio::test]
@@ -46,3 +52,78 @@ async fn amount_to_ui_amount(token_program: Pubkey) {
 
     assert!(account.is_some());
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn amount_to_ui_amount_with_19_decimals(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account with 19 decimals.
+
+    let mint_authority = Pubkey::new_unique();
+    let mint = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_mint2(
+        &spl_token::ID,
+        &mint.pubkey(),
+        &mint_authority,
+        None,
+        19,
+    )
+    .unwrap();
+    initialize_ix.program_id = token_program;
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(spl_token::state::Mint::LEN),
+            spl_token::state::Mint::LEN as u64,
+            &token_program,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When we convert amounts that need more than 18 decimal places.
+
+    for (amount, expected) in [
+        (1, "0.0000000000000000001"),
+        (u64::MAX, "1.8446744073709551615"),
+    ] {
+        let mut amount_to_ui_amount_ix =
+            spl_token::instruction::amount_to_ui_amount(&spl_token::ID, &mint.pubkey(), amount)
+                .unwrap();
+        amount_to_ui_amount_ix.program_id = token_program;
+
+        let tx = Transaction::new_signed_with_payer(
+            &[amount_to_ui_amount_ix],
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+
+        assert!(simulation.result.unwrap().is_ok());
+
+        // Then the UI amount is formatted with all decimal places.
+
+        let return_data = simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .unwrap();
+
+        assert_eq!(return_data.data, expected.as_bytes());
+    }
+}
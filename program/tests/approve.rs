@@ -5,6 +5,7 @@ mod setup;
 use setup::{account, mint, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    program_option::COption,
     program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
@@ -85,3 +86,269 @@ async fn approve(token_program: Pubkey) {
     assert!(account.delegate.unwrap() == delegate);
     assert!(account.delegated_amount == 50);
 }
+
+#[cfg(not(feature = "strict-approve"))]
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn approve_more_than_balance(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we approve a delegate for more than the account balance.
+
+    let delegate = Pubkey::new_unique();
+
+    let mut approve_ix = spl_token::instruction::approve(
+        &spl_token::ID,
+        &account,
+        &delegate,
+        &owner.pubkey(),
+        &[],
+        150,
+    )
+    .unwrap();
+    approve_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the approval succeeds, following SPL Token.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.delegate.is_some());
+    assert!(account.delegate.unwrap() == delegate);
+    assert!(account.delegated_amount == 150);
+}
+
+#[cfg(feature = "strict-approve")]
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn approve_more_than_balance_strict(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we approve a delegate for more than the account balance.
+
+    let delegate = Pubkey::new_unique();
+
+    let mut approve_ix = spl_token::instruction::approve(
+        &spl_token::ID,
+        &account,
+        &delegate,
+        &owner.pubkey(),
+        &[],
+        150,
+    )
+    .unwrap();
+    approve_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[approve_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the approval is rejected with `InsufficientFunds`.
+
+    assert_eq!(
+        error.unwrap(),
+        solana_sdk::transaction::TransactionError::InstructionError(
+            0,
+            solana_sdk::instruction::InstructionError::Custom(
+                spl_token::error::TokenError::InsufficientFunds as u32
+            )
+        )
+    );
+
+    // And the account has no delegate.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.delegate.is_none());
+    assert!(account.delegated_amount == 0);
+}
+
+/// Offset of the `delegated_amount` field in the token account data.
+const DELEGATED_AMOUNT_OFFSET: usize = 121;
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn approve_overwrites_delegated_amount(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    // And a token account with 20,000 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        20_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we approve a delegate for 12,345 tokens.
+
+    let delegate = Pubkey::new_unique();
+
+    account::approve(
+        &mut context,
+        &account,
+        &delegate,
+        &owner,
+        12_345,
+        &token_program,
+    )
+    .await;
+
+    // Then the raw account data stores the amount verbatim.
+
+    let data = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+
+    assert_eq!(
+        &data[DELEGATED_AMOUNT_OFFSET..DELEGATED_AMOUNT_OFFSET + 8],
+        &12_345u64.to_le_bytes()
+    );
+
+    // When we approve a new delegate for 500 tokens.
+
+    let new_delegate = Pubkey::new_unique();
+
+    account::approve(
+        &mut context,
+        &account,
+        &new_delegate,
+        &owner,
+        500,
+        &token_program,
+    )
+    .await;
+
+    // Then the previous delegation is replaced, not accumulated.
+
+    let data = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+
+    assert_eq!(
+        &data[DELEGATED_AMOUNT_OFFSET..DELEGATED_AMOUNT_OFFSET + 8],
+        &500u64.to_le_bytes()
+    );
+
+    let account = spl_token::state::Account::unpack(&data).unwrap();
+
+    assert_eq!(account.delegate, COption::Some(new_delegate));
+    assert_eq!(account.delegated_amount, 500);
+}
@@ -0,0 +1,623 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::InstructionError,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::{error::TokenError, instruction::AuthorityType};
+
+async fn set_authority(
+    context: &mut ProgramTestContext,
+    target: &Pubkey,
+    new_authority: Option<&Pubkey>,
+    authority_type: AuthorityType,
+    authority: &Keypair,
+    program_id: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let mut set_authority_ix = spl_token::instruction::set_authority(
+        &spl_token::ID,
+        target,
+        new_authority,
+        authority_type,
+        &authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    set_authority_ix.program_id = *program_id;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_authority_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn freeze(
+    context: &mut ProgramTestContext,
+    account: &Pubkey,
+    mint: &Pubkey,
+    freeze_authority: &Keypair,
+    program_id: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let mut freeze_account_ix = spl_token::instruction::freeze_account(
+        &spl_token::ID,
+        account,
+        mint,
+        &freeze_authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    freeze_account_ix.program_id = *program_id;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[freeze_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, freeze_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn close(
+    context: &mut ProgramTestContext,
+    account: &Pubkey,
+    authority: &Keypair,
+    program_id: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        account,
+        &authority.pubkey(),
+        &authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = *program_id;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn change_mint_tokens_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account and a token account.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we set a new mint authority.
+
+    let new_authority = Keypair::new();
+
+    set_authority(
+        &mut context,
+        &mint,
+        Some(&new_authority.pubkey()),
+        AuthorityType::MintTokens,
+        &mint_authority,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then the mint has the new mint authority.
+
+    let mint_account = context
+        .banks_client
+        .get_account(mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+
+    assert!(mint_account.mint_authority == COption::Some(new_authority.pubkey()));
+
+    // And the old mint authority cannot mint.
+
+    let error = mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::OwnerMismatch as u32)
+        )
+    );
+
+    // And the new mint authority can mint.
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &new_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let token_account = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert_eq!(token_account.amount, 100);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn clear_mint_tokens_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account and a token account.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we clear the mint authority.
+
+    set_authority(
+        &mut context,
+        &mint,
+        None,
+        AuthorityType::MintTokens,
+        &mint_authority,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then the mint has no mint authority.
+
+    let mint_account = context
+        .banks_client
+        .get_account(mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+
+    assert!(mint_account.mint_authority == COption::None);
+
+    // And the old mint authority cannot mint.
+
+    let error = mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::FixedSupply as u32)
+        )
+    );
+
+    // And the mint authority cannot be set again.
+
+    let error = set_authority(
+        &mut context,
+        &mint,
+        Some(&Pubkey::new_unique()),
+        AuthorityType::MintTokens,
+        &mint_authority,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::FixedSupply as u32)
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn change_freeze_account_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account with a freeze authority and a token account.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority,
+        Some(freeze_authority.pubkey()),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we set a new freeze authority.
+
+    let new_authority = Keypair::new();
+
+    set_authority(
+        &mut context,
+        &mint,
+        Some(&new_authority.pubkey()),
+        AuthorityType::FreezeAccount,
+        &freeze_authority,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then the mint has the new freeze authority.
+
+    let mint_account = context
+        .banks_client
+        .get_account(mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+
+    assert!(mint_account.freeze_authority == COption::Some(new_authority.pubkey()));
+
+    // And the old freeze authority cannot freeze.
+
+    let error = freeze(
+        &mut context,
+        &account,
+        &mint,
+        &freeze_authority,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::OwnerMismatch as u32)
+        )
+    );
+
+    // And the new freeze authority can freeze.
+
+    freeze(
+        &mut context,
+        &account,
+        &mint,
+        &new_authority,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let token_account = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert!(token_account.is_frozen());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn clear_freeze_account_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account with a freeze authority and a token account.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority,
+        Some(freeze_authority.pubkey()),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we clear the freeze authority.
+
+    set_authority(
+        &mut context,
+        &mint,
+        None,
+        AuthorityType::FreezeAccount,
+        &freeze_authority,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then the old freeze authority cannot freeze.
+
+    let error = freeze(
+        &mut context,
+        &account,
+        &mint,
+        &freeze_authority,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::MintCannotFreeze as u32)
+        )
+    );
+
+    // And the freeze authority cannot be set again.
+
+    let error = set_authority(
+        &mut context,
+        &mint,
+        Some(&Pubkey::new_unique()),
+        AuthorityType::FreezeAccount,
+        &freeze_authority,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::MintCannotFreeze as u32)
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn change_account_owner_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account and a token account with a delegate.
+
+    let mint_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(&mut context, mint_authority, None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    account::approve(
+        &mut context,
+        &account,
+        &Pubkey::new_unique(),
+        &owner,
+        50,
+        &token_program,
+    )
+    .await;
+
+    // When we set a new owner.
+
+    let new_owner = Keypair::new();
+
+    set_authority(
+        &mut context,
+        &account,
+        Some(&new_owner.pubkey()),
+        AuthorityType::AccountOwner,
+        &owner,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then the account has the new owner and the delegate is cleared.
+
+    let token_account = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert_eq!(token_account.owner, new_owner.pubkey());
+    assert!(token_account.delegate == COption::None);
+    assert_eq!(token_account.delegated_amount, 0);
+
+    // And the old owner cannot change the owner back.
+
+    let error = set_authority(
+        &mut context,
+        &account,
+        Some(&owner.pubkey()),
+        AuthorityType::AccountOwner,
+        &owner,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::OwnerMismatch as u32)
+        )
+    );
+
+    // And the owner cannot be cleared.
+
+    let error = set_authority(
+        &mut context,
+        &account,
+        None,
+        AuthorityType::AccountOwner,
+        &new_owner,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::InvalidInstruction as u32)
+        )
+    );
+
+    // And the new owner can close the account.
+
+    close(&mut context, &account, &new_owner, &token_program)
+        .await
+        .unwrap();
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_none());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn change_close_account_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account and a token account.
+
+    let mint_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(&mut context, mint_authority, None, &token_program)
+        .await
+        .unwrap();
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    // When we set a close authority.
+
+    let close_authority = Keypair::new();
+
+    set_authority(
+        &mut context,
+        &account,
+        Some(&close_authority.pubkey()),
+        AuthorityType::CloseAccount,
+        &owner,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then the account has the close authority.
+
+    let token_account = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert!(token_account.close_authority == COption::Some(close_authority.pubkey()));
+
+    // And the owner can no longer close the account.
+
+    let error = close(&mut context, &account, &owner, &token_program)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::OwnerMismatch as u32)
+        )
+    );
+
+    // And the close authority can close the account.
+
+    close(&mut context, &account, &close_authority, &token_program)
+        .await
+        .unwrap();
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_none());
+}
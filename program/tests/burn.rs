@@ -5,11 +5,13 @@ mod setup;
 use setup::{account, mint, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    instruction::InstructionError,
     program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
+use spl_token::error::TokenError;
 
 #[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
 #[tokio::test]
@@ -75,3 +77,556 @@ async fn burn(token_program: Pubkey) {
 
     assert!(account.amount == 50);
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn burn_frozen_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority.pubkey()),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a frozen token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    account::freeze(
+        &mut context,
+        &account,
+        &mint,
+        &freeze_authority,
+        &token_program,
+    )
+    .await;
+
+    // When we try to burn 50 tokens.
+
+    let mut burn_ix =
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint, &owner.pubkey(), &[], 50)
+            .unwrap();
+    burn_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix.clone()],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the instruction fails with `AccountFrozen`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::AccountFrozen as u32)
+        )
+    );
+
+    // When we thaw the account and burn again.
+
+    account::thaw(
+        &mut context,
+        &account,
+        &mint,
+        &freeze_authority,
+        &token_program,
+    )
+    .await;
+
+    let last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account should have 50 tokens remaining.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 50);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn burn_with_mint_mismatch(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given two mint accounts.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint_a = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let mint_b = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a mint A token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint_a, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint_a,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we try to burn 50 tokens providing mint B.
+
+    let mut burn_ix =
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint_b, &owner.pubkey(), &[], 50)
+            .unwrap();
+    burn_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the instruction fails with `MintMismatch`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::MintMismatch as u32)
+        )
+    );
+
+    // And the token account and both mint supplies are unchanged.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 100);
+
+    let mint_a = context.banks_client.get_account(mint_a).await.unwrap();
+    assert!(mint_a.is_some());
+
+    let mint_a = mint_a.unwrap();
+    let mint_a = spl_token::state::Mint::unpack(&mint_a.data).unwrap();
+
+    assert!(mint_a.supply == 100);
+
+    let mint_b = context.banks_client.get_account(mint_b).await.unwrap();
+    assert!(mint_b.is_some());
+
+    let mint_b = mint_b.unwrap();
+    let mint_b = spl_token::state::Mint::unpack(&mint_b.data).unwrap();
+
+    assert!(mint_b.supply == 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn burn_with_mint_mismatch_and_insufficient_funds(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given two mint accounts.
+
+    let mint_authority = Keypair::new();
+
+    let mint_a = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    let mint_b = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    // And a mint A token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint_a, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint_a,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we try to burn 150 tokens providing mint B.
+
+    let mut burn_ix =
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint_b, &owner.pubkey(), &[], 150)
+            .unwrap();
+    burn_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `InsufficientFunds` rather than `MintMismatch`, since
+    // `Burn` checks the balance before the mint, as SPL Token does.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::InsufficientFunds as u32)
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn burn_more_than_balance(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we try to burn more tokens than the account holds.
+
+    let mut burn_ix =
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint, &owner.pubkey(), &[], 101)
+            .unwrap();
+    burn_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the burn fails with `InsufficientFunds`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::InsufficientFunds as u32)
+        )
+    );
+
+    // And the account balance is unchanged.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 100);
+
+    // And the mint supply is unchanged.
+
+    let mint = context.banks_client.get_account(mint).await.unwrap();
+
+    assert!(mint.is_some());
+
+    let mint = mint.unwrap();
+    let mint = spl_token::state::Mint::unpack(&mint.data).unwrap();
+
+    assert!(mint.supply == 100);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn burn_and_mint_supply(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account holding the entire supply of 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we burn the entire supply.
+
+    let mut burn_ix =
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint, &owner.pubkey(), &[], 100)
+            .unwrap();
+    burn_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the supply is zero.
+
+    let mint_account = context
+        .banks_client
+        .get_account(mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+
+    assert!(mint_account.supply == 0);
+
+    // And minting and burning in the same transaction keeps the supply
+    // consistent with the account balance.
+
+    let mut mint_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint,
+        &account,
+        &mint_authority.pubkey(),
+        &[],
+        30,
+    )
+    .unwrap();
+    mint_ix.program_id = token_program;
+
+    let mut burn_ix =
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint, &owner.pubkey(), &[], 10)
+            .unwrap();
+    burn_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix, burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let mint_account = context
+        .banks_client
+        .get_account(mint)
+        .await
+        .unwrap()
+        .unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(mint_account.supply == 20);
+    assert!(account.amount == mint_account.supply);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn burn_with_read_only_mint(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we burn tokens with the mint marked as read-only.
+
+    let mut burn_ix =
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint, &owner.pubkey(), &[], 10)
+            .unwrap();
+    burn_ix.program_id = token_program;
+    burn_ix.accounts[1].is_writable = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the burn fails: with `strict-writable` the program rejects the
+    // read-only account, otherwise the runtime rejects the change to it.
+
+    #[cfg(feature = "strict-writable")]
+    let expected = InstructionError::InvalidAccountData;
+    #[cfg(not(feature = "strict-writable"))]
+    let expected = InstructionError::ReadonlyDataModified;
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, expected)
+    );
+}
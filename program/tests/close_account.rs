@@ -5,10 +5,14 @@ mod setup;
 use setup::{account, mint, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    instruction::InstructionError,
+    program_option::COption,
+    program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
+use spl_token::error::TokenError;
 
 #[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
 #[tokio::test]
@@ -65,3 +69,454 @@ async fn close_account(token_program: Pubkey) {
     let token_account = context.banks_client.get_account(account).await.unwrap();
     assert!(token_account.is_none());
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn close_account_with_balance(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we try to close the account.
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &account,
+        &owner.pubkey(),
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the close fails with `NonNativeHasBalance`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::NonNativeHasBalance as u32)
+        )
+    );
+
+    // And the account still exists.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_some());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn close_account_after_burn(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And the tokens are burned.
+
+    let mut burn_ix =
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint, &owner.pubkey(), &[], 100)
+            .unwrap();
+    burn_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When we close the account.
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &account,
+        &owner.pubkey(),
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then an account must not exist.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_none());
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn close_read_only_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    // When we close it marked as read-only.
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &account,
+        &owner.pubkey(),
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+    close_account_ix.accounts[0].is_writable = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the close fails: with `strict-writable` the program rejects the
+    // read-only account, otherwise the runtime rejects the change to it.
+
+    #[cfg(feature = "strict-writable")]
+    let expected = InstructionError::InvalidAccountData;
+    #[cfg(not(feature = "strict-writable"))]
+    let expected = InstructionError::ReadonlyLamportChange;
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, expected)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn close_account_moves_lamports(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    // And a token account.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    // And a destination account with lamports.
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let source_lamports = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let destination_lamports = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // When we close the account.
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &account,
+        &destination,
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the source account has no lamports left, i.e., it no longer exists.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_none());
+
+    // And the destination account received exactly the source lamports, so the
+    // total lamports are conserved.
+
+    let destination_account = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        destination_account.lamports,
+        destination_lamports + source_lamports
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn close_delegated_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    // And a token account with 1,000 tokens approved to a delegate.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        1_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let delegate = Keypair::new();
+
+    account::approve(
+        &mut context,
+        &account,
+        &delegate.pubkey(),
+        &owner,
+        1_000,
+        &token_program,
+    )
+    .await;
+
+    // And the owner moved the tokens out, leaving the delegation in place.
+
+    let other_account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &account,
+        &other_account,
+        &owner.pubkey(),
+        &[],
+        1_000,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert_eq!(token_account.delegate, COption::Some(delegate.pubkey()));
+
+    // When the owner closes the account.
+
+    let destination = Pubkey::new_unique();
+    let account_lamports = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &account,
+        &destination,
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account is closed and its lamports are moved to the destination.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_none());
+
+    let destination_account = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(destination_account.lamports, account_lamports);
+
+    // And the delegate cannot transfer from the closed account.
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &account,
+        &other_account,
+        &delegate.pubkey(),
+        &[],
+        1,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+    );
+}
@@ -0,0 +1,512 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::InstructionError,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::{error::TokenError, state::Account};
+
+async fn transfer(
+    context: &mut ProgramTestContext,
+    source: &Pubkey,
+    destination: &Pubkey,
+    authority: &Keypair,
+    amount: u64,
+    program_id: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        source,
+        destination,
+        &authority.pubkey(),
+        &[],
+        amount,
+    )
+    .unwrap();
+    transfer_ix.program_id = *program_id;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn get_account(context: &mut ProgramTestContext, account: Pubkey) -> Account {
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    Account::unpack(&account.unwrap().data).unwrap()
+}
+
+/// Mints 100 tokens to a new token account and returns the mint and the account.
+async fn setup_account(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    freeze_authority: Option<Pubkey>,
+    program_id: &Pubkey,
+) -> (Pubkey, Pubkey) {
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        context,
+        mint_authority.pubkey(),
+        freeze_authority,
+        program_id,
+    )
+    .await
+    .unwrap();
+
+    let account = account::initialize(context, &mint, owner, program_id).await;
+
+    mint::mint(context, &mint, &account, &mint_authority, 100, program_id)
+        .await
+        .unwrap();
+
+    (mint, account)
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn delegate_lifecycle(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let (mint, account) = setup_account(&mut context, &owner.pubkey(), None, &token_program).await;
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we approve a delegate for 60 tokens.
+
+    let delegate = Keypair::new();
+
+    account::approve(
+        &mut context,
+        &account,
+        &delegate.pubkey(),
+        &owner,
+        60,
+        &token_program,
+    )
+    .await;
+
+    // Then the account has the delegate and delegated amount.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert!(token_account.delegate == COption::Some(delegate.pubkey()));
+    assert_eq!(token_account.delegated_amount, 60);
+
+    // When the delegate transfers 20 tokens.
+
+    transfer(
+        &mut context,
+        &account,
+        &destination,
+        &delegate,
+        20,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then the delegated amount is decremented.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert_eq!(token_account.amount, 80);
+    assert_eq!(token_account.delegated_amount, 40);
+
+    // When the delegate burns 10 tokens.
+
+    let mut burn_ix =
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint, &delegate.pubkey(), &[], 10)
+            .unwrap();
+    burn_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[burn_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the delegated amount is decremented.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert_eq!(token_account.amount, 70);
+    assert_eq!(token_account.delegated_amount, 30);
+
+    // When the delegate tries to transfer more than the delegated amount.
+
+    let error = transfer(
+        &mut context,
+        &account,
+        &destination,
+        &delegate,
+        31,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    // Then the transfer fails with `InsufficientFunds`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::InsufficientFunds as u32)
+        )
+    );
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert_eq!(token_account.amount, 70);
+    assert_eq!(token_account.delegated_amount, 30);
+
+    // When the owner revokes the delegate.
+
+    let mut revoke_ix =
+        spl_token::instruction::revoke(&spl_token::ID, &account, &owner.pubkey(), &[]).unwrap();
+    revoke_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account has no delegate.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert!(token_account.delegate == COption::None);
+    assert_eq!(token_account.delegated_amount, 0);
+
+    // And the former delegate cannot transfer.
+
+    let error = transfer(
+        &mut context,
+        &account,
+        &destination,
+        &delegate,
+        10,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::OwnerMismatch as u32)
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn delegate_cleared_after_full_use(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens and a delegate for 50 tokens.
+
+    let owner = Keypair::new();
+
+    let (mint, account) = setup_account(&mut context, &owner.pubkey(), None, &token_program).await;
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let delegate = Keypair::new();
+
+    account::approve(
+        &mut context,
+        &account,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        &token_program,
+    )
+    .await;
+
+    // When the delegate transfers the whole delegated amount.
+
+    transfer(
+        &mut context,
+        &account,
+        &destination,
+        &delegate,
+        50,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then the delegate is cleared.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert_eq!(token_account.amount, 50);
+    assert!(token_account.delegate == COption::None);
+    assert_eq!(token_account.delegated_amount, 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn approve_replaces_delegate(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens and a delegate for 50 tokens.
+
+    let owner = Keypair::new();
+
+    let (mint, account) = setup_account(&mut context, &owner.pubkey(), None, &token_program).await;
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let delegate = Keypair::new();
+
+    account::approve(
+        &mut context,
+        &account,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        &token_program,
+    )
+    .await;
+
+    // When we approve a different delegate for 30 tokens.
+
+    let new_delegate = Keypair::new();
+
+    account::approve(
+        &mut context,
+        &account,
+        &new_delegate.pubkey(),
+        &owner,
+        30,
+        &token_program,
+    )
+    .await;
+
+    // Then the account has the new delegate and delegated amount.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert!(token_account.delegate == COption::Some(new_delegate.pubkey()));
+    assert_eq!(token_account.delegated_amount, 30);
+
+    // And the previous delegate cannot transfer.
+
+    let error = transfer(
+        &mut context,
+        &account,
+        &destination,
+        &delegate,
+        10,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::OwnerMismatch as u32)
+        )
+    );
+
+    // And the new delegate can transfer.
+
+    transfer(
+        &mut context,
+        &account,
+        &destination,
+        &new_delegate,
+        10,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert_eq!(token_account.delegated_amount, 20);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn delegate_on_frozen_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account with 100 tokens and a delegate for 50 tokens.
+
+    let owner = Keypair::new();
+    let freeze_authority = Keypair::new();
+
+    let (mint, account) = setup_account(
+        &mut context,
+        &owner.pubkey(),
+        Some(freeze_authority.pubkey()),
+        &token_program,
+    )
+    .await;
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    let delegate = Keypair::new();
+
+    account::approve(
+        &mut context,
+        &account,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        &token_program,
+    )
+    .await;
+
+    // And the account is frozen.
+
+    account::freeze(
+        &mut context,
+        &account,
+        &mint,
+        &freeze_authority,
+        &token_program,
+    )
+    .await;
+
+    // When the delegate tries to transfer.
+
+    let error = transfer(
+        &mut context,
+        &account,
+        &destination,
+        &delegate,
+        10,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    // Then the transfer fails with `AccountFrozen`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::AccountFrozen as u32)
+        )
+    );
+
+    // And the delegated amount is unchanged.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert!(token_account.delegate == COption::Some(delegate.pubkey()));
+    assert_eq!(token_account.delegated_amount, 50);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn delegate_cannot_close_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an empty token account with a delegate.
+
+    let owner = Keypair::new();
+
+    let mint = mint::initialize(&mut context, Pubkey::new_unique(), None, &token_program)
+        .await
+        .unwrap();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    let delegate = Keypair::new();
+
+    account::approve(
+        &mut context,
+        &account,
+        &delegate.pubkey(),
+        &owner,
+        50,
+        &token_program,
+    )
+    .await;
+
+    // When the delegate tries to close the account.
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &account,
+        &delegate.pubkey(),
+        &delegate.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &delegate],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the close fails with `OwnerMismatch`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::OwnerMismatch as u32)
+        )
+    );
+
+    // And the account still exists.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_some());
+}
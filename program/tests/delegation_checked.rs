@@ -0,0 +1,221 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use std::mem::size_of;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::InstructionError,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::{error::TokenError, state::Account};
+use token_interface::state::mint::Mint;
+
+/// Number of decimals of the mint used in the tests.
+const DECIMALS: u8 = 6;
+
+/// Creates a mint with [`DECIMALS`] decimals.
+async fn initialize_mint(
+    context: &mut ProgramTestContext,
+    mint_authority: &Pubkey,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let account = Keypair::new();
+
+    let account_size = size_of::<Mint>();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_mint(
+        &spl_token::ID,
+        &account.pubkey(),
+        mint_authority,
+        None,
+        DECIMALS,
+    )
+    .unwrap();
+    initialize_ix.program_id = *program_id;
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(account_size),
+            account_size as u64,
+            program_id,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    account.pubkey()
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn transfer_checked(
+    context: &mut ProgramTestContext,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Keypair,
+    amount: u64,
+    decimals: u8,
+    program_id: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let mut transfer_ix = spl_token::instruction::transfer_checked(
+        &spl_token::ID,
+        source,
+        mint,
+        destination,
+        &authority.pubkey(),
+        &[],
+        amount,
+        decimals,
+    )
+    .unwrap();
+    transfer_ix.program_id = *program_id;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn get_account(context: &mut ProgramTestContext, account: Pubkey) -> Account {
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    Account::unpack(&account.unwrap().data).unwrap()
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn delegate_transfer_checked_round_trip(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint with 6 decimals.
+
+    let mint_authority = Keypair::new();
+
+    let mint = initialize_mint(&mut context, &mint_authority.pubkey(), &token_program).await;
+
+    // And a token account A with 1,000 tokens.
+
+    let owner = Keypair::new();
+
+    let account_a = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account_a,
+        &mint_authority,
+        1_000,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account B.
+
+    let account_b =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // And a delegate approved for 500 tokens.
+
+    let delegate = Keypair::new();
+
+    account::approve(
+        &mut context,
+        &account_a,
+        &delegate.pubkey(),
+        &owner,
+        500,
+        &token_program,
+    )
+    .await;
+
+    let token_account = get_account(&mut context, account_a).await;
+
+    assert_eq!(token_account.delegate, COption::Some(delegate.pubkey()));
+    assert_eq!(token_account.delegated_amount, 500);
+
+    // When the delegate transfers 500 tokens to account B.
+
+    transfer_checked(
+        &mut context,
+        &account_a,
+        &mint,
+        &account_b,
+        &delegate,
+        500,
+        DECIMALS,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then the tokens are transferred and the delegation is exhausted.
+
+    let token_account = get_account(&mut context, account_a).await;
+
+    assert_eq!(token_account.amount, 500);
+    assert_eq!(token_account.delegate, COption::None);
+    assert_eq!(token_account.delegated_amount, 0);
+
+    let token_account = get_account(&mut context, account_b).await;
+
+    assert_eq!(token_account.amount, 500);
+
+    // And the delegate cannot transfer another token.
+
+    let error = transfer_checked(
+        &mut context,
+        &account_a,
+        &mint,
+        &account_b,
+        &delegate,
+        1,
+        DECIMALS,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::OwnerMismatch as u32)
+        )
+    );
+
+    // And the balances are unchanged.
+
+    let token_account = get_account(&mut context, account_a).await;
+
+    assert_eq!(token_account.amount, 500);
+
+    let token_account = get_account(&mut context, account_b).await;
+
+    assert_eq!(token_account.amount, 500);
+}
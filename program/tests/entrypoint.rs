@@ -0,0 +1,163 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+/// Processes an instruction with raw instruction data.
+async fn process_raw(
+    context: &mut ProgramTestContext,
+    data: Vec<u8>,
+    accounts: Vec<AccountMeta>,
+    signers: &[&Keypair],
+    program_id: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let instruction = Instruction {
+        program_id: *program_id,
+        accounts,
+        data,
+    };
+
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID, vec![25] ; "p-token first unknown discriminator")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, vec![0xFF] ; "p-token batch discriminator")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, vec![0xFF, 2, 1, 3, 0, 0, 0, 0, 0, 0, 0] ; "p-token batch discriminator with payload")]
+#[tokio::test]
+async fn unknown_discriminator(token_program: Pubkey, data: Vec<u8>) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // When we process an instruction with a discriminator that is not supported.
+    //
+    // There is no batch instruction, so `0xFF` falls through the first `match`
+    // to `process_remaining_instruction` like any other unknown discriminator.
+
+    let error = process_raw(&mut context, data, vec![], &[], &token_program)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `InvalidInstructionData` rather than panicking.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn empty_instruction_data(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // When we process an instruction without instruction data.
+
+    let error = process_raw(&mut context, vec![], vec![], &[], &token_program)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `InvalidInstructionData`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::InvalidInstructionData)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn discriminator_only_instruction_data(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account with a freeze authority.
+
+    let freeze_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        Pubkey::new_unique(),
+        Some(freeze_authority.pubkey()),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And two token accounts.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+    let other_account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    // When we freeze an account with only the `FreezeAccount` discriminator.
+
+    process_raw(
+        &mut context,
+        vec![10],
+        vec![
+            AccountMeta::new(other_account, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(freeze_authority.pubkey(), true),
+        ],
+        &[&freeze_authority],
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And we close an account with only the `CloseAccount` discriminator.
+
+    process_raw(
+        &mut context,
+        vec![9],
+        vec![
+            AccountMeta::new(account, false),
+            AccountMeta::new(owner.pubkey(), false),
+            AccountMeta::new_readonly(owner.pubkey(), true),
+        ],
+        &[&owner],
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // Then both instructions succeed.
+
+    let token_account = context
+        .banks_client
+        .get_account(other_account)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert!(token_account.is_frozen());
+
+    let closed_account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(closed_account.is_none());
+}
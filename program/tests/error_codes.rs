@@ -0,0 +1,84 @@
+#![cfg(feature = "test-sbf")]
+
+//! Checks that the numeric codes of `TokenError` match the SPL Token codes.
+//!
+//! Changing any of these codes breaks compatibility with SPL Token clients.
+
+use pinocchio::program_error::ProgramError;
+use spl_token::error::TokenError as SplTokenError;
+use token_interface::error::TokenError;
+
+/// Returns the SPL Token error and the code expected for `error`.
+///
+/// The `match` has no wildcard arm, so adding a `TokenError` variant does not
+/// compile until it is mapped to its SPL Token code here.
+fn expected_code(error: &TokenError) -> (SplTokenError, u32) {
+    match error {
+        TokenError::NotRentExempt => (SplTokenError::NotRentExempt, 0),
+        TokenError::InsufficientFunds => (SplTokenError::InsufficientFunds, 1),
+        TokenError::InvalidMint => (SplTokenError::InvalidMint, 2),
+        TokenError::MintMismatch => (SplTokenError::MintMismatch, 3),
+        TokenError::OwnerMismatch => (SplTokenError::OwnerMismatch, 4),
+        TokenError::FixedSupply => (SplTokenError::FixedSupply, 5),
+        TokenError::AlreadyInUse => (SplTokenError::AlreadyInUse, 6),
+        TokenError::InvalidNumberOfProvidedSigners => {
+            (SplTokenError::InvalidNumberOfProvidedSigners, 7)
+        }
+        TokenError::InvalidNumberOfRequiredSigners => {
+            (SplTokenError::InvalidNumberOfRequiredSigners, 8)
+        }
+        TokenError::UninitializedState => (SplTokenError::UninitializedState, 9),
+        TokenError::NativeNotSupported => (SplTokenError::NativeNotSupported, 10),
+        TokenError::NonNativeHasBalance => (SplTokenError::NonNativeHasBalance, 11),
+        TokenError::InvalidInstruction => (SplTokenError::InvalidInstruction, 12),
+        TokenError::InvalidState => (SplTokenError::InvalidState, 13),
+        TokenError::Overflow => (SplTokenError::Overflow, 14),
+        TokenError::AuthorityTypeNotSupported => (SplTokenError::AuthorityTypeNotSupported, 15),
+        TokenError::MintCannotFreeze => (SplTokenError::MintCannotFreeze, 16),
+        TokenError::AccountFrozen => (SplTokenError::AccountFrozen, 17),
+        TokenError::MintDecimalsMismatch => (SplTokenError::MintDecimalsMismatch, 18),
+        TokenError::NonNativeNotSupported => (SplTokenError::NonNativeNotSupported, 19),
+    }
+}
+
+/// Error variants checked by the test.
+const ERRORS: [TokenError; 20] = [
+    TokenError::NotRentExempt,
+    TokenError::InsufficientFunds,
+    TokenError::InvalidMint,
+    TokenError::MintMismatch,
+    TokenError::OwnerMismatch,
+    TokenError::FixedSupply,
+    TokenError::AlreadyInUse,
+    TokenError::InvalidNumberOfProvidedSigners,
+    TokenError::InvalidNumberOfRequiredSigners,
+    TokenError::UninitializedState,
+    TokenError::NativeNotSupported,
+    TokenError::NonNativeHasBalance,
+    TokenError::InvalidInstruction,
+    TokenError::InvalidState,
+    TokenError::Overflow,
+    TokenError::AuthorityTypeNotSupported,
+    TokenError::MintCannotFreeze,
+    TokenError::AccountFrozen,
+    TokenError::MintDecimalsMismatch,
+    TokenError::NonNativeNotSupported,
+];
+
+#[test]
+fn error_codes() {
+    for (index, error) in ERRORS.iter().enumerate() {
+        let (spl_error, code) = expected_code(error);
+
+        // The codes are sequential, so a variant missing from `ERRORS` shows up
+        // as a gap.
+        assert_eq!(code, index as u32, "unexpected position for {error:?}");
+
+        assert_eq!(
+            ProgramError::from(error.clone()),
+            ProgramError::Custom(code),
+            "unexpected code for {error:?}"
+        );
+        assert_eq!(spl_error as u32, code, "unexpected SPL code for {error:?}");
+    }
+}
@@ -5,12 +5,14 @@ mod setup;
 use setup::{account, mint, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    instruction::InstructionError,
     program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    system_instruction,
+    transaction::{Transaction, TransactionError},
 };
-use spl_token::state::AccountState;
+use spl_token::{error::TokenError, native_mint, state::AccountState};
 
 #[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
 #[tokio::test]
@@ -72,3 +74,175 @@ async fn freeze_account(token_program: Pubkey) {
 
     assert_eq!(token_account.state, AccountState::Frozen);
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn freeze_account_with_mint_mismatch(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given two mint accounts with different freeze authorities.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority_a = Keypair::new();
+    let freeze_authority_b = Keypair::new();
+
+    let mint_a = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority_a.pubkey()),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let mint_b = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority_b.pubkey()),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a mint A token account.
+
+    let owner = Pubkey::new_unique();
+
+    let account = account::initialize(&mut context, &mint_a, &owner, &token_program).await;
+
+    // When we try to freeze the account using mint B and its freeze authority.
+
+    let mut freeze_account_ix = spl_token::instruction::freeze_account(
+        &spl_token::ID,
+        &account,
+        &mint_b,
+        &freeze_authority_b.pubkey(),
+        &[],
+    )
+    .unwrap();
+    freeze_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[freeze_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &freeze_authority_b],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the freeze fails with `MintMismatch`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::MintMismatch as u32)
+        )
+    );
+
+    // And the account is not frozen.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.state == AccountState::Initialized);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn freeze_native_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a native token account.
+
+    let owner = Pubkey::new_unique();
+    let account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_account3(
+        &spl_token::ID,
+        &account.pubkey(),
+        &native_mint::id(),
+        &owner,
+    )
+    .unwrap();
+    initialize_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &account.pubkey(),
+                rent.minimum_balance(spl_token::state::Account::LEN) + 1_000,
+                spl_token::state::Account::LEN as u64,
+                &token_program,
+            ),
+            initialize_ix,
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When we try to freeze the account.
+
+    let freeze_authority = Keypair::new();
+
+    let mut freeze_account_ix = spl_token::instruction::freeze_account(
+        &spl_token::ID,
+        &account.pubkey(),
+        &native_mint::id(),
+        &freeze_authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    freeze_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[freeze_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &freeze_authority],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `NativeNotSupported`, following SPL Token: the native
+    // mint has no freeze authority.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::NativeNotSupported as u32)
+        )
+    );
+
+    // And the account is not frozen.
+
+    let token_account = context
+        .banks_client
+        .get_account(account.pubkey())
+        .await
+        .unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert_eq!(token_account.state, AccountState::Initialized);
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{processor, tokio, ProgramTest};
+use solana_sdk::{program_pack::Pack, pubkey::Pubkey, signature::Signer, transaction::Transaction};
+
+/// Simulates a `GetAccountDataSize` instruction and returns the size from the
+/// return data.
+async fn account_data_size(program_test: ProgramTest, token_program: &Pubkey) -> u64 {
+    let mut context = program_test.start_with_context().await;
+
+    // Given a mint account.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority,
+        Some(freeze_authority),
+        token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we simulate the `GetAccountDataSize` instruction.
+
+    let mut get_size_ix =
+        spl_token::instruction::get_account_data_size(&spl_token::ID, &mint).unwrap();
+    // Switches the program id to the token program.
+    get_size_ix.program_id = *token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[get_size_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let simulation = context.banks_client.simulate_transaction(tx).await.unwrap();
+
+    assert!(simulation.result.unwrap().is_ok());
+
+    // Then the return data holds the size as a little-endian `u64`.
+
+    let return_data = simulation
+        .simulation_details
+        .and_then(|details| details.return_data)
+        .unwrap();
+
+    assert_eq!(&return_data.program_id, token_program);
+
+    u64::from_le_bytes(return_data.data.try_into().unwrap())
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn get_account_data_size(token_program: Pubkey) {
+    let p_token = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+
+    let mut spl_token = ProgramTest::new(
+        "spl_token",
+        spl_token::ID,
+        processor!(spl_token::processor::Processor::process),
+    );
+    spl_token.prefer_bpf(false);
+
+    let p_token_size = account_data_size(p_token, &token_program).await;
+    let spl_token_size = account_data_size(spl_token, &spl_token::ID).await;
+
+    // The size of a base token account is returned by both programs.
+
+    assert_eq!(p_token_size, spl_token::state::Account::LEN as u64);
+    assert_eq!(p_token_size, spl_token_size);
+}
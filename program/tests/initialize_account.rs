@@ -89,3 +89,257 @@ async fn initialize_account(token_program: Pubkey) {
     assert!(account.owner == owner);
     assert!(account.mint == mint);
 }
+
+#[cfg(not(feature = "optional-rent-sysvar"))]
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_account_without_rent_sysvar(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority,
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And an `InitializeAccount` instruction without the rent sysvar account.
+
+    let owner = Pubkey::new_unique();
+    let account = Keypair::new();
+
+    let account_size = 165;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_account(
+        &spl_token::ID,
+        &account.pubkey(),
+        &mint,
+        &owner,
+    )
+    .unwrap();
+    // Switches the program id to the token program.
+    initialize_ix.program_id = token_program;
+    // Removes the rent sysvar account.
+    initialize_ix.accounts.pop();
+
+    // When a new token account is created and initialized.
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(account_size),
+            account_size as u64,
+            &token_program,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `NotEnoughAccountKeys`, as in SPL Token.
+
+    assert_eq!(
+        error.unwrap(),
+        solana_sdk::transaction::TransactionError::InstructionError(
+            1,
+            solana_sdk::instruction::InstructionError::NotEnoughAccountKeys
+        )
+    );
+}
+
+#[cfg(feature = "optional-rent-sysvar")]
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_account_with_optional_rent_sysvar(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority,
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And an `InitializeAccount` instruction without the rent sysvar account.
+
+    let owner = Pubkey::new_unique();
+    let account = Keypair::new();
+
+    let account_size = 165;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_account(
+        &spl_token::ID,
+        &account.pubkey(),
+        &mint,
+        &owner,
+    )
+    .unwrap();
+    // Switches the program id to the token program.
+    initialize_ix.program_id = token_program;
+    // Removes the rent sysvar account.
+    initialize_ix.accounts.pop();
+
+    // When a new token account is created and initialized.
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(account_size),
+            account_size as u64,
+            &token_program,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then an account has the correct data.
+
+    let account = context
+        .banks_client
+        .get_account(account.pubkey())
+        .await
+        .unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(!account.is_frozen());
+    assert!(account.owner == owner);
+    assert!(account.mint == mint);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_account_variants_match(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority,
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And an owner.
+
+    let owner = Pubkey::new_unique();
+
+    let account_size = 165;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    // When accounts are initialized with `InitializeAccount`, `InitializeAccount2`
+    // and `InitializeAccount3`.
+
+    let mut data = Vec::new();
+
+    for variant in 0..3 {
+        let account = Keypair::new();
+
+        let mut initialize_ix = match variant {
+            0 => spl_token::instruction::initialize_account(
+                &spl_token::ID,
+                &account.pubkey(),
+                &mint,
+                &owner,
+            ),
+            1 => spl_token::instruction::initialize_account2(
+                &spl_token::ID,
+                &account.pubkey(),
+                &mint,
+                &owner,
+            ),
+            _ => spl_token::instruction::initialize_account3(
+                &spl_token::ID,
+                &account.pubkey(),
+                &mint,
+                &owner,
+            ),
+        }
+        .unwrap();
+        initialize_ix.program_id = token_program;
+
+        let instructions = vec![
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &account.pubkey(),
+                rent.minimum_balance(account_size),
+                account_size as u64,
+                &token_program,
+            ),
+            initialize_ix,
+        ];
+
+        let tx = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&context.payer.pubkey()),
+            &[&context.payer, &account],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+
+        let account = context
+            .banks_client
+            .get_account(account.pubkey())
+            .await
+            .unwrap();
+
+        assert!(account.is_some());
+
+        data.push(account.unwrap().data);
+    }
+
+    // Then all accounts have identical data.
+
+    assert_eq!(data[0], data[1]);
+    assert_eq!(data[0], data[2]);
+}
@@ -0,0 +1,365 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::InstructionError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::{error::TokenError, instruction::AuthorityType};
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_immutable_owner(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority,
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And an owner and an account keypair.
+
+    let owner = Keypair::new();
+    let account = Keypair::new();
+
+    let account_size = 165;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    // When the immutable owner is initialized twice before the account.
+
+    let mut immutable_owner_ix =
+        spl_token::instruction::initialize_immutable_owner(&spl_token::ID, &account.pubkey())
+            .unwrap();
+    immutable_owner_ix.program_id = token_program;
+
+    let mut initialize_ix = spl_token::instruction::initialize_account3(
+        &spl_token::ID,
+        &account.pubkey(),
+        &mint,
+        &owner.pubkey(),
+    )
+    .unwrap();
+    initialize_ix.program_id = token_program;
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(account_size),
+            account_size as u64,
+            &token_program,
+        ),
+        immutable_owner_ix.clone(),
+        immutable_owner_ix.clone(),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+
+    // Then the instruction is a no-op and the account is initialized.
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let token_account = context
+        .banks_client
+        .get_account(account.pubkey())
+        .await
+        .unwrap();
+
+    assert!(token_account.is_some());
+
+    let token_account = token_account.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert!(token_account.owner == owner.pubkey());
+
+    // And initializing the immutable owner on the initialized account fails with
+    // `AlreadyInUse`.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[immutable_owner_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::AlreadyInUse as u32)
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn set_owner_after_initialize_immutable_owner(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority,
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account initialized with an immutable owner.
+
+    let owner = Keypair::new();
+    let account = Keypair::new();
+
+    let account_size = 165;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut immutable_owner_ix =
+        spl_token::instruction::initialize_immutable_owner(&spl_token::ID, &account.pubkey())
+            .unwrap();
+    immutable_owner_ix.program_id = token_program;
+
+    let mut initialize_ix = spl_token::instruction::initialize_account3(
+        &spl_token::ID,
+        &account.pubkey(),
+        &mint,
+        &owner.pubkey(),
+    )
+    .unwrap();
+    initialize_ix.program_id = token_program;
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(account_size),
+            account_size as u64,
+            &token_program,
+        ),
+        immutable_owner_ix,
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // When we set a new owner.
+
+    let new_owner = Pubkey::new_unique();
+
+    let mut set_authority_ix = spl_token::instruction::set_authority(
+        &spl_token::ID,
+        &account.pubkey(),
+        Some(&new_owner),
+        AuthorityType::AccountOwner,
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+    set_authority_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_authority_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the owner is updated, since SPL Token does not support immutable
+    // owners.
+
+    let token_account = context
+        .banks_client
+        .get_account(account.pubkey())
+        .await
+        .unwrap();
+
+    assert!(token_account.is_some());
+
+    let token_account = token_account.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert!(token_account.owner == new_owner);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn transfer_and_close_with_immutable_owner(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And two token accounts initialized with an immutable owner.
+
+    let owner = Keypair::new();
+    let source = Keypair::new();
+    let destination = Keypair::new();
+
+    let account_size = 165;
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut instructions = Vec::new();
+
+    for account in [&source, &destination] {
+        let mut immutable_owner_ix =
+            spl_token::instruction::initialize_immutable_owner(&spl_token::ID, &account.pubkey())
+                .unwrap();
+        immutable_owner_ix.program_id = token_program;
+
+        let mut initialize_ix = spl_token::instruction::initialize_account3(
+            &spl_token::ID,
+            &account.pubkey(),
+            &mint,
+            &owner.pubkey(),
+        )
+        .unwrap();
+        initialize_ix.program_id = token_program;
+
+        instructions.push(system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(account_size),
+            account_size as u64,
+            &token_program,
+        ));
+        instructions.push(immutable_owner_ix);
+        instructions.push(initialize_ix);
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &source, &destination],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // And the source account has 100 tokens.
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &source.pubkey(),
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we transfer all tokens to the destination account.
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &source.pubkey(),
+        &destination.pubkey(),
+        &owner.pubkey(),
+        &[],
+        100,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the destination account has the tokens.
+
+    let account = context
+        .banks_client
+        .get_account(destination.pubkey())
+        .await
+        .unwrap()
+        .unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert_eq!(account.amount, 100);
+
+    // And the owner can close the empty source account.
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &source.pubkey(),
+        &owner.pubkey(),
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(source.pubkey())
+        .await
+        .unwrap();
+
+    assert!(account.is_none());
+}
@@ -4,15 +4,16 @@ mod setup;
 
 use std::mem::size_of;
 
-use setup::TOKEN_PROGRAM_ID;
+use setup::{mint, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    instruction::InstructionError,
     program_option::COption,
     program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
     system_instruction,
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
 use token_interface::state::mint::Mint;
 
@@ -82,3 +83,121 @@ async fn initialize_mint(token_program: Pubkey) {
     assert!(mint.freeze_authority == COption::Some(freeze_authority));
     assert!(mint.decimals == 0)
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_mint_freeze_authority_bytes(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint authority and a freeze authority.
+
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    for freeze_authority in [None, Some(freeze_authority)] {
+        // When a mint is initialized with the freeze authority.
+
+        let mint = mint::initialize(
+            &mut context,
+            mint_authority,
+            freeze_authority,
+            &token_program,
+        )
+        .await
+        .unwrap();
+
+        let account = context.banks_client.get_account(mint).await.unwrap();
+
+        assert!(account.is_some());
+
+        let account = account.unwrap();
+
+        // Then the freeze authority `COption` has the expected raw bytes.
+
+        let mut expected_freeze_authority = [0u8; 36];
+
+        if let Some(freeze_authority) = freeze_authority {
+            expected_freeze_authority[0] = 1;
+            expected_freeze_authority[4..].copy_from_slice(freeze_authority.as_ref());
+        }
+
+        assert_eq!(&account.data[46..82], &expected_freeze_authority);
+
+        // And the account data matches an SPL Token packed mint.
+
+        let mut expected = [0u8; spl_token::state::Mint::LEN];
+        spl_token::state::Mint {
+            mint_authority: COption::Some(mint_authority),
+            supply: 0,
+            decimals: 4,
+            is_initialized: true,
+            freeze_authority: freeze_authority.into(),
+        }
+        .pack_into_slice(&mut expected);
+
+        assert_eq!(account.data, expected);
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_mint_without_rent_sysvar(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint authority and an account keypair.
+
+    let mint_authority = Pubkey::new_unique();
+    let account = Keypair::new();
+
+    let account_size = size_of::<Mint>();
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    // And an `InitializeMint` instruction without the rent sysvar account.
+
+    let mut initialize_ix = spl_token::instruction::initialize_mint(
+        &spl_token::ID,
+        &account.pubkey(),
+        &mint_authority,
+        None,
+        0,
+    )
+    .unwrap();
+    initialize_ix.program_id = token_program;
+    initialize_ix.accounts.truncate(1);
+
+    // When a new mint account is created and initialized.
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(account_size),
+            account_size as u64,
+            &token_program,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `NotEnoughAccountKeys`, unlike `InitializeMint2`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(1, InstructionError::NotEnoughAccountKeys)
+    );
+}
@@ -40,6 +40,12 @@ async fn initialize_multisig(token_program: Pubkey) {
     // Switches the program id to the token program.
     initialize_ix.program_id = token_program;
 
+    // The signers are passed as the accounts after the multisig and rent sysvar.
+    let signer_metas = initialize_ix.accounts[2..]
+        .iter()
+        .map(|meta| meta.pubkey)
+        .collect::<Vec<_>>();
+
     // When a new multisig account is created and initialized.
 
     let instructions = vec![
@@ -77,4 +83,7 @@ async fn initialize_multisig(token_program: Pubkey) {
     assert!(multisig.is_initialized);
     assert_eq!(multisig.n, 3);
     assert_eq!(multisig.m, 2);
+
+    // And the signers match the account metas of the instruction.
+    assert_eq!(multisig.signers[..3], signer_metas[..]);
 }
@@ -5,13 +5,14 @@ mod setup;
 use setup::TOKEN_PROGRAM_ID;
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    instruction::InstructionError,
     program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
     system_instruction,
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
-use spl_token::state::Multisig;
+use spl_token::{error::TokenError, state::Multisig};
 
 #[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
 #[tokio::test]
@@ -78,3 +79,153 @@ async fn initialize_multisig2(token_program: Pubkey) {
     assert_eq!(multisig.n, 3);
     assert_eq!(multisig.m, 2);
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_multisig2_matches_initialize_multisig(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given two accounts and the same set of signers.
+
+    let multisig = Keypair::new();
+    let multisig2 = Keypair::new();
+    let signer1 = Pubkey::new_unique();
+    let signer2 = Pubkey::new_unique();
+    let signer3 = Pubkey::new_unique();
+    let signers = vec![&signer1, &signer2, &signer3];
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_multisig(
+        &spl_token::ID,
+        &multisig.pubkey(),
+        &signers,
+        2,
+    )
+    .unwrap();
+    // Switches the program id to the token program.
+    initialize_ix.program_id = token_program;
+
+    let mut initialize2_ix = spl_token::instruction::initialize_multisig2(
+        &spl_token::ID,
+        &multisig2.pubkey(),
+        &signers,
+        2,
+    )
+    .unwrap();
+    // Switches the program id to the token program.
+    initialize2_ix.program_id = token_program;
+
+    // When the accounts are initialized with `InitializeMultisig` and
+    // `InitializeMultisig2` respectively.
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &multisig.pubkey(),
+            rent.minimum_balance(Multisig::LEN),
+            Multisig::LEN as u64,
+            &token_program,
+        ),
+        initialize_ix,
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &multisig2.pubkey(),
+            rent.minimum_balance(Multisig::LEN),
+            Multisig::LEN as u64,
+            &token_program,
+        ),
+        initialize2_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &multisig, &multisig2],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then both multisig accounts have the same data.
+
+    let account = context
+        .banks_client
+        .get_account(multisig.pubkey())
+        .await
+        .unwrap();
+    assert!(account.is_some());
+
+    let account2 = context
+        .banks_client
+        .get_account(multisig2.pubkey())
+        .await
+        .unwrap();
+    assert!(account2.is_some());
+
+    assert_eq!(account.unwrap().data, account2.unwrap().data);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_multisig2_not_rent_exempt(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an account without enough lamports to be rent exempt.
+
+    let multisig = Keypair::new();
+    let signer1 = Pubkey::new_unique();
+    let signer2 = Pubkey::new_unique();
+    let signer3 = Pubkey::new_unique();
+    let signers = vec![&signer1, &signer2, &signer3];
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_multisig2(
+        &spl_token::ID,
+        &multisig.pubkey(),
+        &signers,
+        2,
+    )
+    .unwrap();
+    // Switches the program id to the token program.
+    initialize_ix.program_id = token_program;
+
+    // When the account is created and initialized.
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &multisig.pubkey(),
+            rent.minimum_balance(Multisig::LEN) - 1,
+            Multisig::LEN as u64,
+            &token_program,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &multisig],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the instruction fails with `NotRentExempt`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(TokenError::NotRentExempt as u32)
+        )
+    );
+}
@@ -0,0 +1,662 @@
+#![cfg(all(feature = "test-sbf", feature = "logging"))]
+
+mod setup;
+
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_token::{
+    instruction::AuthorityType,
+    native_mint,
+    state::{Account, Mint, Multisig},
+};
+
+/// Maximum number of bytes an instruction is expected to log.
+///
+/// Keeping the logs of each instruction small avoids them being truncated when
+/// a transaction executes many instructions.
+const MAX_LOG_BYTES: usize = 256;
+
+/// Prefix of the messages logged by a program.
+const LOG_PREFIX: &str = "Program log: ";
+
+/// Messages logged by an instruction.
+struct InstructionLogs {
+    /// Name of the instruction.
+    name: &'static str,
+
+    /// Messages logged by the program, without the [`LOG_PREFIX`].
+    messages: Vec<String>,
+}
+
+/// Switches the program id of a token instruction to the program under test.
+fn token_instruction(
+    instruction: Result<Instruction, ProgramError>,
+    program_id: &Pubkey,
+) -> Instruction {
+    let mut instruction = instruction.unwrap();
+    instruction.program_id = *program_id;
+    instruction
+}
+
+fn create_account(
+    context: &ProgramTestContext,
+    rent: &Rent,
+    account: &Keypair,
+    space: usize,
+    program_id: &Pubkey,
+) -> Instruction {
+    system_instruction::create_account(
+        &context.payer.pubkey(),
+        &account.pubkey(),
+        rent.minimum_balance(space),
+        space as u64,
+        program_id,
+    )
+}
+
+/// Processes the `instructions` and records the messages logged by the token
+/// program under `name`.
+async fn process(
+    context: &mut ProgramTestContext,
+    logs: &mut Vec<InstructionLogs>,
+    name: &'static str,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+
+    assert!(result.result.is_ok(), "{name} failed: {:?}", result.result);
+
+    // The system program does not log messages, so all messages are from the
+    // token program.
+    let messages = result
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .filter_map(|log| log.strip_prefix(LOG_PREFIX))
+        .map(String::from)
+        .collect();
+
+    logs.push(InstructionLogs { name, messages });
+}
+
+/// Runs each token instruction once and returns the logged messages.
+async fn run_instructions(token_program: &Pubkey) -> Vec<InstructionLogs> {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let mut logs = Vec::new();
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Keypair::new();
+    let owner = Keypair::new();
+    let delegate = Pubkey::new_unique();
+
+    // Mints.
+
+    let mint = Keypair::new();
+    let instructions = [
+        create_account(&context, &rent, &mint, Mint::LEN, token_program),
+        token_instruction(
+            spl_token::instruction::initialize_mint(
+                &spl_token::ID,
+                &mint.pubkey(),
+                &mint_authority.pubkey(),
+                Some(&freeze_authority.pubkey()),
+                4,
+            ),
+            token_program,
+        ),
+    ];
+    process(
+        &mut context,
+        &mut logs,
+        "InitializeMint",
+        &instructions,
+        &[&mint],
+    )
+    .await;
+    let mint = mint.pubkey();
+
+    let mint2 = Keypair::new();
+    let instructions = [
+        create_account(&context, &rent, &mint2, Mint::LEN, token_program),
+        token_instruction(
+            spl_token::instruction::initialize_mint2(
+                &spl_token::ID,
+                &mint2.pubkey(),
+                &mint_authority.pubkey(),
+                None,
+                4,
+            ),
+            token_program,
+        ),
+    ];
+    process(
+        &mut context,
+        &mut logs,
+        "InitializeMint2",
+        &instructions,
+        &[&mint2],
+    )
+    .await;
+    let mint2 = mint2.pubkey();
+
+    // Token accounts.
+
+    let account = Keypair::new();
+    let instructions = [
+        create_account(&context, &rent, &account, Account::LEN, token_program),
+        token_instruction(
+            spl_token::instruction::initialize_account(
+                &spl_token::ID,
+                &account.pubkey(),
+                &mint,
+                &owner.pubkey(),
+            ),
+            token_program,
+        ),
+    ];
+    process(
+        &mut context,
+        &mut logs,
+        "InitializeAccount",
+        &instructions,
+        &[&account],
+    )
+    .await;
+    let account = account.pubkey();
+
+    let account2 = Keypair::new();
+    let instructions = [
+        create_account(&context, &rent, &account2, Account::LEN, token_program),
+        token_instruction(
+            spl_token::instruction::initialize_account2(
+                &spl_token::ID,
+                &account2.pubkey(),
+                &mint,
+                &owner.pubkey(),
+            ),
+            token_program,
+        ),
+    ];
+    process(
+        &mut context,
+        &mut logs,
+        "InitializeAccount2",
+        &instructions,
+        &[&account2],
+    )
+    .await;
+    let account2 = account2.pubkey();
+
+    let account3 = Keypair::new();
+    let instructions = [
+        create_account(&context, &rent, &account3, Account::LEN, token_program),
+        token_instruction(
+            spl_token::instruction::initialize_account3(
+                &spl_token::ID,
+                &account3.pubkey(),
+                &mint,
+                &owner.pubkey(),
+            ),
+            token_program,
+        ),
+    ];
+    process(
+        &mut context,
+        &mut logs,
+        "InitializeAccount3",
+        &instructions,
+        &[&account3],
+    )
+    .await;
+    let account3 = account3.pubkey();
+
+    let immutable_account = Keypair::new();
+    let instructions = [
+        create_account(
+            &context,
+            &rent,
+            &immutable_account,
+            Account::LEN,
+            token_program,
+        ),
+        token_instruction(
+            spl_token::instruction::initialize_immutable_owner(
+                &spl_token::ID,
+                &immutable_account.pubkey(),
+            ),
+            token_program,
+        ),
+    ];
+    process(
+        &mut context,
+        &mut logs,
+        "InitializeImmutableOwner",
+        &instructions,
+        &[&immutable_account],
+    )
+    .await;
+
+    let native_account = Keypair::new();
+    let instructions = [
+        create_account(
+            &context,
+            &rent,
+            &native_account,
+            Account::LEN,
+            token_program,
+        ),
+        token_instruction(
+            spl_token::instruction::initialize_account3(
+                &spl_token::ID,
+                &native_account.pubkey(),
+                &native_mint::id(),
+                &owner.pubkey(),
+            ),
+            token_program,
+        ),
+    ];
+    process(
+        &mut context,
+        &mut logs,
+        "InitializeAccount3",
+        &instructions,
+        &[&native_account],
+    )
+    .await;
+    let native_account = native_account.pubkey();
+
+    // Multisig accounts.
+
+    let signer_keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let signers = signer_keys.iter().collect::<Vec<_>>();
+
+    let multisig = Keypair::new();
+    let instructions = [
+        create_account(&context, &rent, &multisig, Multisig::LEN, token_program),
+        token_instruction(
+            spl_token::instruction::initialize_multisig(
+                &spl_token::ID,
+                &multisig.pubkey(),
+                &signers,
+                1,
+            ),
+            token_program,
+        ),
+    ];
+    process(
+        &mut context,
+        &mut logs,
+        "InitializeMultisig",
+        &instructions,
+        &[&multisig],
+    )
+    .await;
+
+    let multisig2 = Keypair::new();
+    let instructions = [
+        create_account(&context, &rent, &multisig2, Multisig::LEN, token_program),
+        token_instruction(
+            spl_token::instruction::initialize_multisig2(
+                &spl_token::ID,
+                &multisig2.pubkey(),
+                &signers,
+                1,
+            ),
+            token_program,
+        ),
+    ];
+    process(
+        &mut context,
+        &mut logs,
+        "InitializeMultisig2",
+        &instructions,
+        &[&multisig2],
+    )
+    .await;
+
+    // Token operations.
+
+    let instructions = [token_instruction(
+        spl_token::instruction::mint_to(
+            &spl_token::ID,
+            &mint,
+            &account,
+            &mint_authority.pubkey(),
+            &[],
+            1_000,
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "MintTo",
+        &instructions,
+        &[&mint_authority],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::mint_to_checked(
+            &spl_token::ID,
+            &mint,
+            &account,
+            &mint_authority.pubkey(),
+            &[],
+            1_000,
+            4,
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "MintToChecked",
+        &instructions,
+        &[&mint_authority],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::transfer(
+            &spl_token::ID,
+            &account,
+            &account2,
+            &owner.pubkey(),
+            &[],
+            100,
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "Transfer",
+        &instructions,
+        &[&owner],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::transfer_checked(
+            &spl_token::ID,
+            &account,
+            &mint,
+            &account2,
+            &owner.pubkey(),
+            &[],
+            100,
+            4,
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "TransferChecked",
+        &instructions,
+        &[&owner],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::approve(
+            &spl_token::ID,
+            &account,
+            &delegate,
+            &owner.pubkey(),
+            &[],
+            100,
+        ),
+        token_program,
+    )];
+    process(&mut context, &mut logs, "Approve", &instructions, &[&owner]).await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::approve_checked(
+            &spl_token::ID,
+            &account,
+            &mint,
+            &delegate,
+            &owner.pubkey(),
+            &[],
+            100,
+            4,
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "ApproveChecked",
+        &instructions,
+        &[&owner],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::revoke(&spl_token::ID, &account, &owner.pubkey(), &[]),
+        token_program,
+    )];
+    process(&mut context, &mut logs, "Revoke", &instructions, &[&owner]).await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::burn(&spl_token::ID, &account, &mint, &owner.pubkey(), &[], 100),
+        token_program,
+    )];
+    process(&mut context, &mut logs, "Burn", &instructions, &[&owner]).await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::burn_checked(
+            &spl_token::ID,
+            &account,
+            &mint,
+            &owner.pubkey(),
+            &[],
+            100,
+            4,
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "BurnChecked",
+        &instructions,
+        &[&owner],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::freeze_account(
+            &spl_token::ID,
+            &account2,
+            &mint,
+            &freeze_authority.pubkey(),
+            &[],
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "FreezeAccount",
+        &instructions,
+        &[&freeze_authority],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::thaw_account(
+            &spl_token::ID,
+            &account2,
+            &mint,
+            &freeze_authority.pubkey(),
+            &[],
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "ThawAccount",
+        &instructions,
+        &[&freeze_authority],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::set_authority(
+            &spl_token::ID,
+            &mint2,
+            Some(&Pubkey::new_unique()),
+            AuthorityType::MintTokens,
+            &mint_authority.pubkey(),
+            &[],
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "SetAuthority",
+        &instructions,
+        &[&mint_authority],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::close_account(
+            &spl_token::ID,
+            &account3,
+            &context.payer.pubkey(),
+            &owner.pubkey(),
+            &[],
+        ),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "CloseAccount",
+        &instructions,
+        &[&owner],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::sync_native(&spl_token::ID, &native_account),
+        token_program,
+    )];
+    process(&mut context, &mut logs, "SyncNative", &instructions, &[]).await;
+
+    // Queries.
+
+    let instructions = [token_instruction(
+        spl_token::instruction::get_account_data_size(&spl_token::ID, &mint),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "GetAccountDataSize",
+        &instructions,
+        &[],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::amount_to_ui_amount(&spl_token::ID, &mint, 100),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "AmountToUiAmount",
+        &instructions,
+        &[],
+    )
+    .await;
+
+    let instructions = [token_instruction(
+        spl_token::instruction::ui_amount_to_amount(&spl_token::ID, &mint, "0.01"),
+        token_program,
+    )];
+    process(
+        &mut context,
+        &mut logs,
+        "UiAmountToAmount",
+        &instructions,
+        &[],
+    )
+    .await;
+
+    logs
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn instruction_logs_fit_budget(token_program: Pubkey) {
+    // When we run each instruction with logging enabled.
+
+    let logs = run_instructions(&token_program).await;
+
+    // Then the messages logged by each instruction fit in the budget.
+
+    for InstructionLogs { name, messages } in logs {
+        let logged_bytes: usize = messages.iter().map(String::len).sum();
+
+        assert!(
+            logged_bytes <= MAX_LOG_BYTES,
+            "{name} logged {logged_bytes} bytes (max {MAX_LOG_BYTES}): {messages:?}"
+        );
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn instruction_logs_contain_name(token_program: Pubkey) {
+    // When we run each instruction with logging enabled.
+
+    let logs = run_instructions(&token_program).await;
+
+    // Then the messages logged by each instruction contain its name.
+
+    for InstructionLogs { name, messages } in logs {
+        let expected = format!("Instruction: {name}");
+
+        assert!(
+            messages.iter().any(|message| message == &expected),
+            "{name} did not log its name: {messages:?}"
+        );
+    }
+}
@@ -2,14 +2,16 @@
 
 mod setup;
 
-use setup::{account, mint, TOKEN_PROGRAM_ID};
+use setup::{account, mint, multisig, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    instruction::InstructionError,
     program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
+use spl_token::{error::TokenError, state::AccountState};
 
 #[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
 #[tokio::test]
@@ -71,3 +73,406 @@ async fn mint_to(token_program: Pubkey) {
 
     assert!(account.amount == 100);
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn mint_to_frozen_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority.pubkey()),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a frozen token account.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    account::freeze(
+        &mut context,
+        &account,
+        &mint,
+        &freeze_authority,
+        &token_program,
+    )
+    .await;
+
+    // When we try to mint tokens to the frozen account.
+
+    let error = mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    // Then the instruction fails with `AccountFrozen`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::AccountFrozen as u32)
+        )
+    );
+
+    // And the mint supply is unchanged.
+
+    let mint_account = context.banks_client.get_account(mint).await.unwrap();
+    assert!(mint_account.is_some());
+
+    let mint_account = mint_account.unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+
+    assert_eq!(mint_account.supply, 0);
+
+    // And the account remains frozen without tokens.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_some());
+
+    let token_account = token_account.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert_eq!(token_account.state, AccountState::Frozen);
+    assert_eq!(token_account.amount, 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn mint_to_supply_overflow(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with `u64::MAX - 1` tokens.
+
+    let owner = Pubkey::new_unique();
+
+    let account = account::initialize(&mut context, &mint, &owner, &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        u64::MAX - 1,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we try to mint 2 tokens.
+
+    let error = mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        2,
+        &token_program,
+    )
+    .await
+    .unwrap_err();
+
+    // Then the mint fails with `Overflow`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::Overflow as u32)
+        )
+    );
+
+    // And minting the remaining token fills the supply to `u64::MAX`.
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        1,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let mint_account = context.banks_client.get_account(mint).await.unwrap();
+
+    assert!(mint_account.is_some());
+
+    let mint_account = mint_account.unwrap();
+    let mint_account = spl_token::state::Mint::unpack(&mint_account.data).unwrap();
+
+    assert!(mint_account.supply == u64::MAX);
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == u64::MAX);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID, 1 ; "p-token 1 of 3 signers")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 2 ; "p-token 2 of 3 signers")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 3 ; "p-token 3 of 3 signers")]
+#[tokio::test]
+async fn mint_to_with_multisig_authority(token_program: Pubkey, signed: usize) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 2 of 3 multisig.
+
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<_>>();
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        2,
+        &token_program,
+    )
+    .await;
+
+    // And a mint account with the multisig as the mint authority.
+
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        multisig,
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account.
+
+    let owner = Pubkey::new_unique();
+
+    let account = account::initialize(&mut context, &mint, &owner, &token_program).await;
+
+    // When we mint tokens signed by `signed` multisig signers.
+
+    let signing_keys = signer_keys[..signed].iter().collect::<Vec<_>>();
+
+    let mut mint_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint,
+        &account,
+        &multisig,
+        &signing_keys,
+        100,
+    )
+    .unwrap();
+    mint_ix.program_id = token_program;
+
+    let mut tx_signers = vec![&context.payer];
+    tx_signers.extend(signers[..signed].iter());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&context.payer.pubkey()),
+        &tx_signers[..],
+        context.last_blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    if signed < 2 {
+        // Then the mint fails without enough signers.
+
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+        );
+        assert!(account.amount == 0);
+    } else {
+        // Then the mint succeeds.
+
+        result.unwrap();
+        assert!(account.amount == 100);
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn mint_to_without_authority_signature(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account.
+
+    let owner = Pubkey::new_unique();
+
+    let account = account::initialize(&mut context, &mint, &owner, &token_program).await;
+
+    // When we try to mint tokens without the mint authority signature.
+
+    let mut mint_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint,
+        &account,
+        &mint_authority.pubkey(),
+        &[],
+        100,
+    )
+    .unwrap();
+    mint_ix.program_id = token_program;
+    mint_ix.accounts[2].is_signer = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the mint fails with `MissingRequiredSignature`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+
+    // And no tokens were minted.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn mint_to_read_only_destination(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    // When we mint tokens to it marked as read-only.
+
+    let mut mint_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint,
+        &account,
+        &mint_authority.pubkey(),
+        &[],
+        100,
+    )
+    .unwrap();
+    mint_ix.program_id = token_program;
+    mint_ix.accounts[1].is_writable = false;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &mint_authority],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the mint fails: with `strict-writable` the program rejects the
+    // read-only account, otherwise the runtime rejects the change to it.
+
+    #[cfg(feature = "strict-writable")]
+    let expected = InstructionError::InvalidAccountData;
+    #[cfg(not(feature = "strict-writable"))]
+    let expected = InstructionError::ReadonlyDataModified;
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, expected)
+    );
+}
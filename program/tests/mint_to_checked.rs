@@ -2,13 +2,14 @@
 
 mod setup;
 
-use setup::{account, mint, TOKEN_PROGRAM_ID};
+use setup::{account, mint, multisig, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    instruction::InstructionError,
     program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
 
 #[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
@@ -72,3 +73,94 @@ async fn mint_to_checked(token_program: Pubkey) {
 
     assert!(account.amount == 100);
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID, 1 ; "p-token 1 of 3 signers")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 2 ; "p-token 2 of 3 signers")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 3 ; "p-token 3 of 3 signers")]
+#[tokio::test]
+async fn mint_to_checked_with_multisig_authority(token_program: Pubkey, signed: usize) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 2 of 3 multisig.
+
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<_>>();
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        2,
+        &token_program,
+    )
+    .await;
+
+    // And a mint account with the multisig as the mint authority.
+
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        multisig,
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account.
+
+    let owner = Pubkey::new_unique();
+
+    let account = account::initialize(&mut context, &mint, &owner, &token_program).await;
+
+    // When we mint tokens signed by `signed` multisig signers.
+
+    let signing_keys = signer_keys[..signed].iter().collect::<Vec<_>>();
+
+    let mut mint_ix = spl_token::instruction::mint_to_checked(
+        &spl_token::ID,
+        &mint,
+        &account,
+        &multisig,
+        &signing_keys,
+        100,
+        4,
+    )
+    .unwrap();
+    mint_ix.program_id = token_program;
+
+    let mut tx_signers = vec![&context.payer];
+    tx_signers.extend(signers[..signed].iter());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&context.payer.pubkey()),
+        &tx_signers[..],
+        context.last_blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    if signed < 2 {
+        // Then the mint fails without enough signers.
+
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+        );
+        assert!(account.amount == 0);
+    } else {
+        // Then the mint succeeds.
+
+        result.unwrap();
+        assert!(account.amount == 100);
+    }
+}
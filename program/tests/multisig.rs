@@ -0,0 +1,468 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, multisig, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    instruction::InstructionError,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::instruction::AuthorityType;
+
+#[test_case::test_case(TOKEN_PROGRAM_ID, 1, 1 ; "p-token 1 of 1")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 3, 2 ; "p-token 2 of 3")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 11, 11 ; "p-token 11 of 11")]
+#[tokio::test]
+async fn initialize_multisig_with_signers(token_program: Pubkey, n: usize, m: u8) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given `n` signers.
+
+    let signer_keys = (0..n).map(|_| Pubkey::new_unique()).collect::<Vec<_>>();
+
+    // When we initialize an `m` of `n` multisig.
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        m,
+        &token_program,
+    )
+    .await;
+
+    // Then the multisig stores the signers and the threshold.
+
+    let account = context.banks_client.get_account(multisig).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let multisig = spl_token::state::Multisig::unpack(&account.data).unwrap();
+
+    assert!(multisig.is_initialized);
+    assert_eq!(multisig.n as usize, n);
+    assert_eq!(multisig.m, m);
+    assert_eq!(multisig.signers[..n], signer_keys[..]);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID, 1, 1 ; "p-token 1 of 1")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 3, 2 ; "p-token 2 of 3")]
+#[tokio::test]
+async fn multisig_as_mint_authority(token_program: Pubkey, n: usize, m: u8) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an `m` of `n` multisig.
+
+    let signers = (0..n).map(|_| Keypair::new()).collect::<Vec<_>>();
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<_>>();
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        m,
+        &token_program,
+    )
+    .await;
+
+    // And a mint account with the multisig as the mint authority.
+
+    let mint = mint::initialize(&mut context, multisig, None, &token_program)
+        .await
+        .unwrap();
+
+    // And a token account.
+
+    let account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we mint tokens signed by `m` multisig signers.
+
+    let signing_keys = signer_keys[..m as usize].iter().collect::<Vec<_>>();
+
+    let mut mint_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint,
+        &account,
+        &multisig,
+        &signing_keys,
+        100,
+    )
+    .unwrap();
+    mint_ix.program_id = token_program;
+
+    let mut tx_signers = vec![&context.payer];
+    tx_signers.extend(signers[..m as usize].iter());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&context.payer.pubkey()),
+        &tx_signers[..],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are minted.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert_eq!(account.amount, 100);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID, 1, 1 ; "p-token 1 of 1")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 3, 2 ; "p-token 2 of 3")]
+#[tokio::test]
+async fn multisig_as_account_owner(token_program: Pubkey, n: usize, m: u8) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an `m` of `n` multisig.
+
+    let signers = (0..n).map(|_| Keypair::new()).collect::<Vec<_>>();
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<_>>();
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        m,
+        &token_program,
+    )
+    .await;
+
+    // And a mint account.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(&mut context, mint_authority.pubkey(), None, &token_program)
+        .await
+        .unwrap();
+
+    // And a token account owned by the multisig with 100 tokens.
+
+    let source = account::initialize(&mut context, &mint, &multisig, &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &source,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a destination account.
+
+    let destination =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we transfer tokens signed by `m` multisig signers.
+
+    let signing_keys = signer_keys[..m as usize].iter().collect::<Vec<_>>();
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &source,
+        &destination,
+        &multisig,
+        &signing_keys,
+        100,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+
+    let mut tx_signers = vec![&context.payer];
+    tx_signers.extend(signers[..m as usize].iter());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &tx_signers[..],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are transferred.
+
+    let account = context.banks_client.get_account(destination).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert_eq!(account.amount, 100);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn multisig_with_not_enough_signers(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given an 11 of 11 multisig where the payer is one of the signers.
+    //
+    // The payer signs as a multisig signer so the transaction fits in the
+    // packet size limit; a transaction with all 11 signers plus a separate
+    // payer would not.
+
+    let signers = (0..10).map(|_| Keypair::new()).collect::<Vec<_>>();
+    let mut signer_keys = vec![context.payer.pubkey()];
+    signer_keys.extend(signers.iter().map(|s| s.pubkey()));
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        11,
+        &token_program,
+    )
+    .await;
+
+    // And a mint account with the multisig as the mint authority.
+
+    let mint = mint::initialize(&mut context, multisig, None, &token_program)
+        .await
+        .unwrap();
+
+    // And a token account.
+
+    let account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we mint tokens signed by only 10 of the multisig signers.
+
+    let signing_keys = signer_keys[..10].iter().collect::<Vec<_>>();
+
+    let mut mint_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint,
+        &account,
+        &multisig,
+        &signing_keys,
+        100,
+    )
+    .unwrap();
+    mint_ix.program_id = token_program;
+
+    let mut tx_signers = vec![&context.payer];
+    tx_signers.extend(signers[..9].iter());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&context.payer.pubkey()),
+        &tx_signers[..],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the mint fails with `MissingRequiredSignature`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn multisig_with_duplicate_signer(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 2 of 3 multisig.
+
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<_>>();
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        2,
+        &token_program,
+    )
+    .await;
+
+    // And a mint account with the multisig as the mint authority.
+
+    let mint = mint::initialize(&mut context, multisig, None, &token_program)
+        .await
+        .unwrap();
+
+    // And a token account.
+
+    let account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we mint tokens with the same signer provided twice.
+
+    let mut mint_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &mint,
+        &account,
+        &multisig,
+        &[&signer_keys[0], &signer_keys[0]],
+        100,
+    )
+    .unwrap();
+    mint_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[mint_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signers[0]],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the duplicate only counts once and the mint fails.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn set_authority_with_multisig_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 2 of 3 multisig.
+
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<_>>();
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        2,
+        &token_program,
+    )
+    .await;
+
+    // And a mint account with the multisig as the mint authority.
+
+    let mint = mint::initialize(&mut context, multisig, None, &token_program)
+        .await
+        .unwrap();
+
+    // When the multisig sets a new mint authority.
+
+    let new_authority = Pubkey::new_unique();
+
+    let mut set_authority_ix = spl_token::instruction::set_authority(
+        &spl_token::ID,
+        &mint,
+        Some(&new_authority),
+        AuthorityType::MintTokens,
+        &multisig,
+        &[&signer_keys[1], &signer_keys[2]],
+    )
+    .unwrap();
+    set_authority_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[set_authority_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signers[1], &signers[2]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the mint has the new mint authority.
+
+    let account = context.banks_client.get_account(mint).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let mint = spl_token::state::Mint::unpack(&account.data).unwrap();
+
+    assert!(mint.mint_authority == COption::Some(new_authority));
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn close_account_with_multisig_owner(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a 2 of 3 multisig.
+
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<_>>();
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        2,
+        &token_program,
+    )
+    .await;
+
+    // And a token account owned by the multisig.
+
+    let mint = mint::initialize(&mut context, Pubkey::new_unique(), None, &token_program)
+        .await
+        .unwrap();
+
+    let account = account::initialize(&mut context, &mint, &multisig, &token_program).await;
+
+    // When the multisig closes the account.
+
+    let destination = Pubkey::new_unique();
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &account,
+        &destination,
+        &multisig,
+        &[&signer_keys[0], &signer_keys[2]],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &signers[0], &signers[2]],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account is closed and the lamports moved to the destination.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+    assert!(account.is_none());
+
+    let destination = context.banks_client.get_account(destination).await.unwrap();
+    assert!(destination.is_some());
+}
@@ -0,0 +1,226 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::{error::TokenError, native_mint, state::Account};
+
+/// Creates a native token account with `extra_lamports` above the rent-exempt
+/// minimum.
+async fn initialize_native_account(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    extra_lamports: u64,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_account3(
+        &spl_token::ID,
+        &account.pubkey(),
+        &native_mint::id(),
+        owner,
+    )
+    .unwrap();
+    initialize_ix.program_id = *program_id;
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(Account::LEN) + extra_lamports,
+            Account::LEN as u64,
+            program_id,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    account.pubkey()
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn get_lamports(context: &mut ProgramTestContext, account: Pubkey) -> u64 {
+    context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .map_or(0, |account| account.lamports)
+}
+
+/// Asserts that the amount of a native account is `expected_amount` and matches
+/// its lamports above the rent-exempt minimum.
+async fn assert_native_amount(
+    context: &mut ProgramTestContext,
+    account: Pubkey,
+    expected_amount: u64,
+) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let token_account = Account::unpack(&account.data).unwrap();
+
+    assert!(token_account.is_native());
+    assert_eq!(token_account.amount, expected_amount);
+    assert_eq!(
+        token_account.amount,
+        account.lamports - rent.minimum_balance(Account::LEN)
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn native_token_lifecycle(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a native account created with 1,000 lamports above the rent-exempt
+    // minimum.
+
+    let owner = Keypair::new();
+
+    let account =
+        initialize_native_account(&mut context, &owner.pubkey(), 1_000, &token_program).await;
+
+    assert_native_amount(&mut context, account, 1_000).await;
+
+    // When we deposit 500 lamports and sync the account.
+
+    let deposit_ix = system_instruction::transfer(&context.payer.pubkey(), &account, 500);
+
+    process(&mut context, deposit_ix, &[]).await.unwrap();
+
+    let mut sync_native_ix = spl_token::instruction::sync_native(&spl_token::ID, &account).unwrap();
+    sync_native_ix.program_id = token_program;
+
+    process(&mut context, sync_native_ix, &[]).await.unwrap();
+
+    // Then the amount includes the deposit.
+
+    assert_native_amount(&mut context, account, 1_500).await;
+
+    // When we transfer 600 tokens to another native account.
+
+    let destination =
+        initialize_native_account(&mut context, &Pubkey::new_unique(), 0, &token_program).await;
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        &spl_token::ID,
+        &account,
+        &destination,
+        &owner.pubkey(),
+        &[],
+        600,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+
+    process(&mut context, transfer_ix, &[&owner]).await.unwrap();
+
+    // Then the lamports are moved with the tokens.
+
+    assert_native_amount(&mut context, account, 900).await;
+    assert_native_amount(&mut context, destination, 600).await;
+
+    // When we try to burn 100 tokens.
+
+    let mut burn_ix = spl_token::instruction::burn(
+        &spl_token::ID,
+        &account,
+        &native_mint::id(),
+        &owner.pubkey(),
+        &[],
+        100,
+    )
+    .unwrap();
+    burn_ix.program_id = token_program;
+
+    let error = process(&mut context, burn_ix, &[&owner]).await.unwrap_err();
+
+    // Then it fails with `NativeNotSupported`, since burning would leave the
+    // lamports in the account, and the account is unchanged.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::NativeNotSupported as u32)
+        )
+    );
+
+    assert_native_amount(&mut context, account, 900).await;
+
+    // When we close the account with its remaining balance.
+
+    let recipient = Pubkey::new_unique();
+    let account_lamports = get_lamports(&mut context, account).await;
+
+    let mut close_account_ix = spl_token::instruction::close_account(
+        &spl_token::ID,
+        &account,
+        &recipient,
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+    close_account_ix.program_id = token_program;
+
+    process(&mut context, close_account_ix, &[&owner])
+        .await
+        .unwrap();
+
+    // Then all its lamports, including the token amount, are moved to the
+    // recipient.
+
+    assert!(context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .is_none());
+
+    assert_eq!(
+        get_lamports(&mut context, recipient).await,
+        account_lamports
+    );
+}
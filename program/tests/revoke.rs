@@ -89,3 +89,77 @@ async fn revoke(token_program: Pubkey) {
     assert!(account.delegate.is_none());
     assert!(account.delegated_amount == 0);
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn revoke_without_delegate(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a token account with 100 tokens and no delegate.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(token_account.is_some());
+
+    let data = token_account.unwrap().data;
+
+    // When we revoke the delegate.
+
+    let mut revoke_ix =
+        spl_token::instruction::revoke(&spl_token::ID, &account, &owner.pubkey(), &[]).unwrap();
+    revoke_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[revoke_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the revoke succeeds and the account data is unchanged.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(token_account.is_some());
+
+    let token_account = token_account.unwrap();
+
+    assert_eq!(token_account.data, data);
+
+    let token_account = spl_token::state::Account::unpack(&token_account.data).unwrap();
+
+    assert!(token_account.delegate.is_none());
+    assert!(token_account.delegated_amount == 0);
+}
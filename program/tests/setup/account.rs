@@ -3,6 +3,7 @@ use solana_sdk::{
     pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction,
     transaction::Transaction,
 };
+use token_interface::state::account_size::BASE_ACCOUNT_SIZE;
 
 pub async fn initialize(
     context: &mut ProgramTestContext,
@@ -12,7 +13,7 @@ pub async fn initialize(
 ) -> Pubkey {
     let account = Keypair::new();
 
-    let account_size = 165;
+    let account_size = BASE_ACCOUNT_SIZE;
     let rent = context.banks_client.get_rent().await.unwrap();
 
     let mut initialize_ix =
@@ -95,3 +96,29 @@ pub async fn freeze(
     );
     context.banks_client.process_transaction(tx).await.unwrap();
 }
+
+pub async fn thaw(
+    context: &mut ProgramTestContext,
+    account: &Pubkey,
+    mint: &Pubkey,
+    freeze_authority: &Keypair,
+    program_id: &Pubkey,
+) {
+    let mut thaw_account_ix = spl_token::instruction::thaw_account(
+        &spl_token::ID,
+        account,
+        mint,
+        &freeze_authority.pubkey(),
+        &[],
+    )
+    .unwrap();
+    thaw_account_ix.program_id = *program_id;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[thaw_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, freeze_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
@@ -4,5 +4,13 @@ use solana_sdk::pubkey::Pubkey;
 pub mod account;
 #[allow(dead_code)]
 pub mod mint;
+#[allow(dead_code)]
+pub mod multisig;
 
-pub const TOKEN_PROGRAM_ID: Pubkey = Pubkey::new_from_array(token_interface::program::ID);
+/// Address of the token program under test.
+///
+/// Tests load the `token_program` SBF binary (p-token) at this address, which is
+/// the same as the SPL Token program address, replacing the SPL Token program that
+/// `ProgramTest` adds by default. Every `test_case` using this constant therefore
+/// runs against p-token.
+pub const TOKEN_PROGRAM_ID: Pubkey = Pubkey::new_from_array(token_interface::TOKEN_PROGRAM_ID);
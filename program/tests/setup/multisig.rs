@@ -0,0 +1,43 @@
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction,
+    transaction::Transaction,
+};
+use spl_token::state::Multisig;
+
+pub async fn initialize(
+    context: &mut ProgramTestContext,
+    signers: &[&Pubkey],
+    m: u8,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let multisig = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix =
+        spl_token::instruction::initialize_multisig(&spl_token::ID, &multisig.pubkey(), signers, m)
+            .unwrap();
+    initialize_ix.program_id = *program_id;
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &multisig.pubkey(),
+            rent.minimum_balance(Multisig::LEN),
+            Multisig::LEN as u64,
+            program_id,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &multisig],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    multisig.pubkey()
+}
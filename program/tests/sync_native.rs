@@ -0,0 +1,326 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account as SolanaAccount, AccountSharedData},
+    instruction::{AccountMeta, InstructionError},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::{error::TokenError, native_mint, state::Account};
+
+/// Creates a native token account with `extra_lamports` above the rent-exempt
+/// minimum.
+async fn initialize_native_account(
+    context: &mut ProgramTestContext,
+    owner: &Pubkey,
+    extra_lamports: u64,
+    program_id: &Pubkey,
+) -> Pubkey {
+    let account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    let mut initialize_ix = spl_token::instruction::initialize_account3(
+        &spl_token::ID,
+        &account.pubkey(),
+        &native_mint::id(),
+        owner,
+    )
+    .unwrap();
+    initialize_ix.program_id = *program_id;
+
+    let instructions = vec![
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(Account::LEN) + extra_lamports,
+            Account::LEN as u64,
+            program_id,
+        ),
+        initialize_ix,
+    ];
+
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &account],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    account.pubkey()
+}
+
+async fn sync_native(
+    context: &mut ProgramTestContext,
+    account: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<(), BanksClientError> {
+    let mut sync_native_ix = spl_token::instruction::sync_native(&spl_token::ID, account).unwrap();
+    sync_native_ix.program_id = *program_id;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sync_native_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn get_account(context: &mut ProgramTestContext, account: Pubkey) -> Account {
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    Account::unpack(&account.unwrap().data).unwrap()
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sync_native_non_native_account(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a token account of a non-native mint.
+
+    let mint = mint::initialize(&mut context, Pubkey::new_unique(), None, &token_program)
+        .await
+        .unwrap();
+
+    let account =
+        account::initialize(&mut context, &mint, &Pubkey::new_unique(), &token_program).await;
+
+    // When we sync it.
+
+    let error = sync_native(&mut context, &account, &token_program)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `NonNativeNotSupported`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::NonNativeNotSupported as u32)
+        )
+    );
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sync_native_with_rent_exempt_balance(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a native account with exactly the rent-exempt minimum.
+
+    let account =
+        initialize_native_account(&mut context, &Pubkey::new_unique(), 0, &token_program).await;
+
+    // When we sync it.
+
+    sync_native(&mut context, &account, &token_program)
+        .await
+        .unwrap();
+
+    // Then the amount is zero.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert!(token_account.is_native());
+    assert_eq!(token_account.amount, 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sync_native_after_lamports_transfer(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a native account with 500 lamports above the rent-exempt minimum.
+
+    let account =
+        initialize_native_account(&mut context, &Pubkey::new_unique(), 500, &token_program).await;
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert_eq!(token_account.amount, 500);
+
+    // And 1000 lamports transferred to it with the system program.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &context.payer.pubkey(),
+            &account,
+            1_000,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The amount is only updated on sync.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert_eq!(token_account.amount, 500);
+
+    // When we sync it.
+
+    sync_native(&mut context, &account, &token_program)
+        .await
+        .unwrap();
+
+    // Then the amount is the lamports above the rent-exempt minimum.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert_eq!(token_account.amount, 1_500);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sync_native_does_not_change_supply(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given the native mint.
+
+    let mut data = vec![0; spl_token::state::Mint::LEN];
+    spl_token::state::Mint::pack(
+        spl_token::state::Mint {
+            mint_authority: COption::None,
+            supply: 0,
+            decimals: native_mint::DECIMALS,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        },
+        &mut data,
+    )
+    .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+
+    context.set_account(
+        &native_mint::id(),
+        &AccountSharedData::from(SolanaAccount {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: token_program,
+            executable: false,
+            rent_epoch: 0,
+        }),
+    );
+
+    // And a native account with 500 lamports above the rent-exempt minimum.
+
+    let account =
+        initialize_native_account(&mut context, &Pubkey::new_unique(), 500, &token_program).await;
+
+    // When we sync it.
+
+    sync_native(&mut context, &account, &token_program)
+        .await
+        .unwrap();
+
+    // Then the native mint supply is unchanged.
+
+    let mint = context
+        .banks_client
+        .get_account(native_mint::id())
+        .await
+        .unwrap();
+
+    assert!(mint.is_some());
+
+    let mint = spl_token::state::Mint::unpack(&mint.unwrap().data).unwrap();
+
+    assert_eq!(mint.supply, 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sync_native_with_extra_accounts(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a native account with 500 lamports above the rent-exempt minimum.
+
+    let account =
+        initialize_native_account(&mut context, &Pubkey::new_unique(), 500, &token_program).await;
+
+    // When we sync it passing additional accounts.
+
+    let mut sync_native_ix = spl_token::instruction::sync_native(&spl_token::ID, &account).unwrap();
+    sync_native_ix.program_id = token_program;
+    sync_native_ix
+        .accounts
+        .push(AccountMeta::new_readonly(Pubkey::new_unique(), false));
+    sync_native_ix
+        .accounts
+        .push(AccountMeta::new(Pubkey::new_unique(), false));
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sync_native_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the additional accounts are ignored and the account is synced.
+
+    let token_account = get_account(&mut context, account).await;
+
+    assert_eq!(token_account.amount, 500);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn sync_native_without_accounts(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // When we sync without passing any account.
+
+    let mut sync_native_ix =
+        spl_token::instruction::sync_native(&spl_token::ID, &Pubkey::new_unique()).unwrap();
+    sync_native_ix.program_id = token_program;
+    sync_native_ix.accounts.clear();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sync_native_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `NotEnoughAccountKeys`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
@@ -5,12 +5,13 @@ mod setup;
 use setup::{account, mint, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    instruction::InstructionError,
     program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
-use spl_token::state::AccountState;
+use spl_token::{error::TokenError, state::AccountState};
 
 #[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
 #[tokio::test]
@@ -81,3 +82,91 @@ async fn thaw_account(token_program: Pubkey) {
 
     assert_eq!(token_account.state, AccountState::Initialized);
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn thaw_account_requires_freeze_authority(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account with a freeze authority.
+
+    let freeze_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        Pubkey::new_unique(),
+        Some(freeze_authority.pubkey()),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a frozen token account whose owner is not the freeze authority.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint, &owner.pubkey(), &token_program).await;
+
+    account::freeze(
+        &mut context,
+        &account,
+        &mint,
+        &freeze_authority,
+        &token_program,
+    )
+    .await;
+
+    // When the owner tries to thaw the account.
+
+    let mut thaw_account_ix =
+        spl_token::instruction::thaw_account(&spl_token::ID, &account, &mint, &owner.pubkey(), &[])
+            .unwrap();
+    thaw_account_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[thaw_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `OwnerMismatch` and the account remains frozen.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::OwnerMismatch as u32)
+        )
+    );
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert_eq!(token_account.state, AccountState::Frozen);
+
+    // When the freeze authority thaws the account.
+
+    account::thaw(
+        &mut context,
+        &account,
+        &mint,
+        &freeze_authority,
+        &token_program,
+    )
+    .await;
+
+    // Then the account is no longer frozen.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    let token_account = spl_token::state::Account::unpack(&token_account.unwrap().data).unwrap();
+
+    assert_eq!(token_account.state, AccountState::Initialized);
+}
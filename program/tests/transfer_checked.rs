@@ -2,14 +2,16 @@
 
 mod setup;
 
-use setup::{account, mint, TOKEN_PROGRAM_ID};
+use setup::{account, mint, multisig, TOKEN_PROGRAM_ID};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{
+    instruction::InstructionError,
     program_pack::Pack,
     pubkey::Pubkey,
     signature::{Keypair, Signer},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
 };
+use spl_token::error::TokenError;
 
 #[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
 #[tokio::test]
@@ -88,3 +90,225 @@ async fn transfer_checked(token_program: Pubkey) {
 
     assert!(account.amount == 0);
 }
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn transfer_checked_with_mint_mismatch(token_program: Pubkey) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given two mint accounts.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint_a = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    let mint_b = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a mint A token account with 100 tokens.
+
+    let owner = Keypair::new();
+
+    let account = account::initialize(&mut context, &mint_a, &owner.pubkey(), &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint_a,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we try to transfer the tokens to a mint B token account using
+    // the source account mint.
+
+    let destination = Pubkey::new_unique();
+
+    let destination_account =
+        account::initialize(&mut context, &mint_b, &destination, &token_program).await;
+
+    let mut transfer_ix = spl_token::instruction::transfer_checked(
+        &spl_token::ID,
+        &account,
+        &mint_a,
+        &destination_account,
+        &owner.pubkey(),
+        &[],
+        100,
+        4,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then the transfer fails with `MintMismatch`.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::MintMismatch as u32)
+        )
+    );
+
+    // And no tokens were moved.
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    assert!(account.amount == 100);
+
+    let destination_account = context
+        .banks_client
+        .get_account(destination_account)
+        .await
+        .unwrap();
+
+    assert!(destination_account.is_some());
+
+    let destination_account = destination_account.unwrap();
+    let destination_account = spl_token::state::Account::unpack(&destination_account.data).unwrap();
+
+    assert!(destination_account.amount == 0);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID, 1 ; "p-token 1 of 3 signers")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 2 ; "p-token 2 of 3 signers")]
+#[test_case::test_case(TOKEN_PROGRAM_ID, 3 ; "p-token 3 of 3 signers")]
+#[tokio::test]
+async fn transfer_checked_with_multisig_owner(token_program: Pubkey, signed: usize) {
+    let mut context = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        Some(freeze_authority),
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // And a 2 of 3 multisig.
+
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let signer_keys = signers.iter().map(|s| s.pubkey()).collect::<Vec<_>>();
+
+    let multisig = multisig::initialize(
+        &mut context,
+        &signer_keys.iter().collect::<Vec<_>>(),
+        2,
+        &token_program,
+    )
+    .await;
+
+    // And a token account owned by the multisig with 100 tokens.
+
+    let account = account::initialize(&mut context, &mint, &multisig, &token_program).await;
+
+    mint::mint(
+        &mut context,
+        &mint,
+        &account,
+        &mint_authority,
+        100,
+        &token_program,
+    )
+    .await
+    .unwrap();
+
+    // When we transfer the tokens signed by `signed` multisig signers.
+
+    let destination = Pubkey::new_unique();
+
+    let destination_account =
+        account::initialize(&mut context, &mint, &destination, &token_program).await;
+
+    let signing_keys = signer_keys[..signed].iter().collect::<Vec<_>>();
+
+    let mut transfer_ix = spl_token::instruction::transfer_checked(
+        &spl_token::ID,
+        &account,
+        &mint,
+        &destination_account,
+        &multisig,
+        &signing_keys,
+        100,
+        4,
+    )
+    .unwrap();
+    transfer_ix.program_id = token_program;
+
+    let mut tx_signers = vec![&context.payer];
+    tx_signers.extend(signers[..signed].iter());
+
+    let tx = Transaction::new_signed_with_payer(
+        &[transfer_ix],
+        Some(&context.payer.pubkey()),
+        &tx_signers[..],
+        context.last_blockhash,
+    );
+    let result = context.banks_client.process_transaction(tx).await;
+
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    let account = account.unwrap();
+    let account = spl_token::state::Account::unpack(&account.data).unwrap();
+
+    if signed < 2 {
+        // Then the transfer fails without enough signers.
+
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+        );
+        assert!(account.amount == 100);
+    } else {
+        // Then the tokens are transferred.
+
+        result.unwrap();
+        assert!(account.amount == 0);
+    }
+}
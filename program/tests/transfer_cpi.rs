@@ -0,0 +1,221 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use setup::{account, mint, TOKEN_PROGRAM_ID};
+use solana_program_test::{processor, tokio, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program::invoke_signed,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::state::Account;
+
+/// Seed of the program-derived authority of the wrapper program.
+const AUTHORITY_SEED: &[u8] = b"authority";
+
+/// Address of the wrapper program.
+const WRAPPER_PROGRAM_ID: Pubkey = Pubkey::new_from_array([7; 32]);
+
+/// A wrapper program that transfers tokens from an account owned by its
+/// program-derived authority.
+///
+/// Accounts expected:
+///   0. `[writable]` The source account.
+///   1. `[writable]` The destination account.
+///   2. `[]` The program-derived authority.
+///   3. `[]` The token program.
+///
+/// The instruction data is the amount (`u64`) followed by a flag (`u8`) indicating
+/// whether the authority signs the CPI with its seeds.
+fn process_wrapper(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [source_info, destination_info, authority_info, token_program_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let amount = u64::from_le_bytes(data[..8].try_into().unwrap());
+    let signed = data[8] == 1;
+
+    let (authority, bump) = Pubkey::find_program_address(&[AUTHORITY_SEED], program_id);
+    assert_eq!(authority_info.key, &authority);
+
+    let mut transfer_ix = spl_token::instruction::transfer(
+        token_program_info.key,
+        source_info.key,
+        destination_info.key,
+        authority_info.key,
+        &[],
+        amount,
+    )
+    .unwrap();
+    transfer_ix.accounts[2].is_signer = signed;
+
+    invoke_signed(
+        &transfer_ix,
+        &[
+            source_info.clone(),
+            destination_info.clone(),
+            authority_info.clone(),
+            token_program_info.clone(),
+        ],
+        &[&[AUTHORITY_SEED, &[bump]]],
+    )
+}
+
+/// Creates a wrapper instruction to transfer `amount` tokens.
+fn wrapper_transfer(
+    source: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+    signed: bool,
+) -> Instruction {
+    let mut data = amount.to_le_bytes().to_vec();
+    data.push(signed as u8);
+
+    Instruction {
+        program_id: WRAPPER_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*source, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*authority, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data,
+    }
+}
+
+async fn start_with_wrapper() -> ProgramTestContext {
+    let mut program_test = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+    program_test.add_builtin_program(
+        "wrapper_program",
+        WRAPPER_PROGRAM_ID,
+        processor!(process_wrapper).unwrap(),
+    );
+    program_test.start_with_context().await
+}
+
+/// Creates a mint and returns the source and destination accounts, where the
+/// source account is owned by the wrapper authority and holds 100 tokens.
+async fn setup_accounts(
+    context: &mut ProgramTestContext,
+    authority: &Pubkey,
+    token_program: &Pubkey,
+) -> (Pubkey, Pubkey) {
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(context, mint_authority.pubkey(), None, token_program)
+        .await
+        .unwrap();
+
+    let source = account::initialize(context, &mint, authority, token_program).await;
+
+    mint::mint(context, &mint, &source, &mint_authority, 100, token_program)
+        .await
+        .unwrap();
+
+    let destination =
+        account::initialize(context, &mint, &Pubkey::new_unique(), token_program).await;
+
+    (source, destination)
+}
+
+async fn get_account(context: &mut ProgramTestContext, account: Pubkey) -> Account {
+    let account = context.banks_client.get_account(account).await.unwrap();
+
+    assert!(account.is_some());
+
+    Account::unpack(&account.unwrap().data).unwrap()
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn transfer_with_program_derived_owner(token_program: Pubkey) {
+    let mut context = start_with_wrapper().await;
+
+    // Given a token account with 100 tokens owned by the wrapper program authority.
+
+    let (authority, _) = Pubkey::find_program_address(&[AUTHORITY_SEED], &WRAPPER_PROGRAM_ID);
+
+    let (source, destination) = setup_accounts(&mut context, &authority, &token_program).await;
+
+    // When the wrapper program transfers the tokens via CPI signing with the
+    // authority seeds.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[wrapper_transfer(
+            &source,
+            &destination,
+            &authority,
+            &token_program,
+            100,
+            true,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the tokens are transferred.
+
+    let source_account = get_account(&mut context, source).await;
+    assert_eq!(source_account.amount, 0);
+
+    let destination_account = get_account(&mut context, destination).await;
+    assert_eq!(destination_account.amount, 100);
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn transfer_with_program_derived_owner_not_signed(token_program: Pubkey) {
+    let mut context = start_with_wrapper().await;
+
+    // Given a token account with 100 tokens owned by the wrapper program authority.
+
+    let (authority, _) = Pubkey::find_program_address(&[AUTHORITY_SEED], &WRAPPER_PROGRAM_ID);
+
+    let (source, destination) = setup_accounts(&mut context, &authority, &token_program).await;
+
+    // When the wrapper program transfers the tokens via CPI without the
+    // authority as a signer.
+
+    let tx = Transaction::new_signed_with_payer(
+        &[wrapper_transfer(
+            &source,
+            &destination,
+            &authority,
+            &token_program,
+            100,
+            false,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let error = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    // Then it fails with `MissingRequiredSignature` and no tokens are transferred.
+
+    assert_eq!(
+        error.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+
+    let source_account = get_account(&mut context, source).await;
+    assert_eq!(source_account.amount, 100);
+
+    let destination_account = get_account(&mut context, destination).await;
+    assert_eq!(destination_account.amount, 0);
+}
@@ -0,0 +1,900 @@
+#![cfg(feature = "test-sbf")]
+
+//! Checks that the token program handles instructions hand-encoded following the
+//! layouts documented on `TokenInstruction` the same way as the instructions
+//! built by the SPL Token helpers.
+//!
+//! Each instruction is processed twice, on two copies of the same initial state:
+//! once built by the `spl_token::instruction` helper and once with accounts and
+//! data encoded by hand. Both runs must produce the same result, return data and
+//! account state.
+
+mod setup;
+
+use setup::TOKEN_PROGRAM_ID;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    account::Account as SolanaAccount,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    native_token::LAMPORTS_PER_SOL,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    signer::keypair::keypair_from_seed,
+    sysvar,
+    transaction::{Transaction, TransactionError},
+};
+use spl_token::{
+    error::TokenError,
+    instruction::{self, AuthorityType},
+    native_mint,
+    state::{Account, AccountState, Mint, Multisig},
+};
+
+/// Initialized mint with 4 decimals and 1,000 tokens of supply.
+const MINT: Pubkey = Pubkey::new_from_array([1; 32]);
+
+/// Token account of [`MINT`] with 1,000 tokens.
+const ACCOUNT: Pubkey = Pubkey::new_from_array([2; 32]);
+
+/// Empty token account of [`MINT`].
+const DESTINATION: Pubkey = Pubkey::new_from_array([3; 32]);
+
+/// Frozen token account of [`MINT`].
+const FROZEN_ACCOUNT: Pubkey = Pubkey::new_from_array([4; 32]);
+
+/// Native token account with 500 lamports above its rent-exempt reserve that
+/// are not synced yet.
+const NATIVE_ACCOUNT: Pubkey = Pubkey::new_from_array([5; 32]);
+
+/// Uninitialized account with the size of a mint.
+const NEW_MINT: Pubkey = Pubkey::new_from_array([6; 32]);
+
+/// Uninitialized account with the size of a token account.
+const NEW_ACCOUNT: Pubkey = Pubkey::new_from_array([7; 32]);
+
+/// Uninitialized account with the size of a multisig.
+const NEW_MULTISIG: Pubkey = Pubkey::new_from_array([8; 32]);
+
+/// Delegate and new authority used by the instructions.
+const DELEGATE: Pubkey = Pubkey::new_from_array([9; 32]);
+
+/// Signers of the multisig.
+const SIGNERS: [Pubkey; 2] = [
+    Pubkey::new_from_array([10; 32]),
+    Pubkey::new_from_array([11; 32]),
+];
+
+/// Number of decimals of [`MINT`].
+const DECIMALS: u8 = 4;
+
+/// Owner of the token accounts and mint and freeze authority of [`MINT`].
+///
+/// The keypair is derived from a fixed seed, so both runs of an instruction sign
+/// with the same key.
+fn owner() -> Keypair {
+    keypair_from_seed(&[12; 32]).unwrap()
+}
+
+/// Addresses compared after each run.
+fn addresses() -> [Pubkey; 9] {
+    [
+        MINT,
+        ACCOUNT,
+        DESTINATION,
+        FROZEN_ACCOUNT,
+        NATIVE_ACCOUNT,
+        NEW_MINT,
+        NEW_ACCOUNT,
+        NEW_MULTISIG,
+        owner().pubkey(),
+    ]
+}
+
+/// Returns an account owned by the token program holding `data`.
+fn program_account(lamports: u64, data: Vec<u8>) -> SolanaAccount {
+    SolanaAccount {
+        lamports,
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Returns an account owned by the token program holding the packed `state`.
+fn packed<T: Pack>(lamports: u64, state: T) -> SolanaAccount {
+    let mut data = vec![0; T::LEN];
+    T::pack(state, &mut data).unwrap();
+    program_account(lamports, data)
+}
+
+/// Returns the initial state of the accounts used by the tests.
+fn initial_accounts() -> Vec<(Pubkey, SolanaAccount)> {
+    let owner = owner().pubkey();
+
+    let mint = Mint {
+        mint_authority: COption::Some(owner),
+        supply: 1_000,
+        decimals: DECIMALS,
+        is_initialized: true,
+        freeze_authority: COption::Some(owner),
+    };
+
+    let account = |mint, amount, state, is_native| Account {
+        mint,
+        owner,
+        amount,
+        delegate: COption::None,
+        state,
+        is_native,
+        delegated_amount: 0,
+        close_authority: COption::None,
+    };
+
+    let rent_exempt_reserve = Rent::default().minimum_balance(Account::LEN);
+
+    vec![
+        (MINT, packed(LAMPORTS_PER_SOL, mint)),
+        (
+            ACCOUNT,
+            packed(
+                LAMPORTS_PER_SOL,
+                account(MINT, 1_000, AccountState::Initialized, COption::None),
+            ),
+        ),
+        (
+            DESTINATION,
+            packed(
+                LAMPORTS_PER_SOL,
+                account(MINT, 0, AccountState::Initialized, COption::None),
+            ),
+        ),
+        (
+            FROZEN_ACCOUNT,
+            packed(
+                LAMPORTS_PER_SOL,
+                account(MINT, 0, AccountState::Frozen, COption::None),
+            ),
+        ),
+        (
+            NATIVE_ACCOUNT,
+            packed(
+                rent_exempt_reserve + 500,
+                account(
+                    native_mint::id(),
+                    0,
+                    AccountState::Initialized,
+                    COption::Some(rent_exempt_reserve),
+                ),
+            ),
+        ),
+        (
+            NEW_MINT,
+            program_account(LAMPORTS_PER_SOL, vec![0; Mint::LEN]),
+        ),
+        (
+            NEW_ACCOUNT,
+            program_account(LAMPORTS_PER_SOL, vec![0; Account::LEN]),
+        ),
+        (
+            NEW_MULTISIG,
+            program_account(LAMPORTS_PER_SOL, vec![0; Multisig::LEN]),
+        ),
+    ]
+}
+
+/// Result of processing an instruction.
+#[derive(Debug, PartialEq)]
+struct Outcome {
+    result: Result<(), TransactionError>,
+    return_data: Option<(Pubkey, Vec<u8>)>,
+    accounts: Vec<Option<SolanaAccount>>,
+}
+
+/// Processes `instruction` on a new copy of the initial state.
+async fn process(instruction: Instruction) -> Outcome {
+    let mut program_test = ProgramTest::new("token_program", TOKEN_PROGRAM_ID, None);
+
+    for (address, account) in initial_accounts() {
+        program_test.add_account(address, account);
+    }
+
+    let mut context = program_test.start_with_context().await;
+
+    let owner = owner();
+    let mut signers = vec![&context.payer];
+
+    if instruction
+        .accounts
+        .iter()
+        .any(|meta| meta.is_signer && meta.pubkey == owner.pubkey())
+    {
+        signers.push(&owner);
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &signers,
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+
+    let return_data = result
+        .metadata
+        .and_then(|metadata| metadata.return_data)
+        .map(|return_data| (return_data.program_id, return_data.data));
+
+    let mut accounts = Vec::new();
+
+    for address in addresses() {
+        accounts.push(context.banks_client.get_account(address).await.unwrap());
+    }
+
+    Outcome {
+        result: result.result,
+        return_data,
+        accounts,
+    }
+}
+
+/// Processes the instruction built by the helper and the hand-encoded one, and
+/// checks that they have the same outcome.
+///
+/// Returns the result of the instruction, so tests can check that it is the
+/// expected one rather than two identical failures.
+async fn assert_same_outcome(
+    mut helper: Instruction,
+    hand_encoded: Instruction,
+    token_program: &Pubkey,
+) -> Result<(), TransactionError> {
+    // Switches the program id to the token program.
+    helper.program_id = *token_program;
+
+    let expected = process(helper).await;
+    let actual = process(hand_encoded).await;
+
+    assert_eq!(actual, expected);
+
+    expected.result
+}
+
+/// Encodes a discriminator followed by the given fields.
+fn encode(discriminator: u8, fields: &[&[u8]]) -> Vec<u8> {
+    let mut data = vec![discriminator];
+    fields
+        .iter()
+        .for_each(|field| data.extend_from_slice(field));
+    data
+}
+
+/// Returns a hand-encoded instruction for the token program.
+fn hand_encoded(token_program: &Pubkey, accounts: Vec<AccountMeta>, data: Vec<u8>) -> Instruction {
+    Instruction {
+        program_id: *token_program,
+        accounts,
+        data,
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_mint(token_program: Pubkey) {
+    let owner = owner().pubkey();
+
+    // When we initialize a mint with `InitializeMint`.
+    //
+    // Data: discriminator (`0`), decimals (`u8`), mint authority (`Pubkey`) and
+    // freeze authority (`COption<Pubkey>`, with a 1-byte tag).
+
+    let result = assert_same_outcome(
+        instruction::initialize_mint(&spl_token::ID, &NEW_MINT, &owner, Some(&owner), 4).unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(NEW_MINT, false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+            ],
+            encode(0, &[&[4], owner.as_ref(), &[1], owner.as_ref()]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    // Then both instructions succeed with the same state.
+
+    assert_eq!(result, Ok(()));
+
+    // When we initialize a mint without a freeze authority with `InitializeMint2`.
+
+    let result = assert_same_outcome(
+        instruction::initialize_mint2(&spl_token::ID, &NEW_MINT, &owner, None, 4).unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![AccountMeta::new(NEW_MINT, false)],
+            encode(20, &[&[4], owner.as_ref(), &[0]]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    // Then both instructions succeed with the same state.
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_account(token_program: Pubkey) {
+    let owner = owner().pubkey();
+
+    // When we initialize an account with `InitializeAccount`, which takes the
+    // owner as an account.
+
+    let result = assert_same_outcome(
+        instruction::initialize_account(&spl_token::ID, &NEW_ACCOUNT, &MINT, &owner).unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(NEW_ACCOUNT, false),
+                AccountMeta::new_readonly(MINT, false),
+                AccountMeta::new_readonly(owner, false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+            ],
+            encode(1, &[]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    assert_eq!(result, Ok(()));
+
+    // And with `InitializeAccount2`, which takes the owner in the data.
+
+    let result = assert_same_outcome(
+        instruction::initialize_account2(&spl_token::ID, &NEW_ACCOUNT, &MINT, &owner).unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(NEW_ACCOUNT, false),
+                AccountMeta::new_readonly(MINT, false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+            ],
+            encode(16, &[owner.as_ref()]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    assert_eq!(result, Ok(()));
+
+    // And with `InitializeAccount3`, which does not take the rent sysvar.
+
+    let result = assert_same_outcome(
+        instruction::initialize_account3(&spl_token::ID, &NEW_ACCOUNT, &MINT, &owner).unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(NEW_ACCOUNT, false),
+                AccountMeta::new_readonly(MINT, false),
+            ],
+            encode(18, &[owner.as_ref()]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    // Then all instructions succeed with the same state.
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn initialize_multisig(token_program: Pubkey) {
+    // When we initialize a 2 of 2 multisig with `InitializeMultisig`.
+    //
+    // Data: discriminator and the number of required signers (`u8`); the signers
+    // are the remaining accounts.
+
+    let result = assert_same_outcome(
+        instruction::initialize_multisig(
+            &spl_token::ID,
+            &NEW_MULTISIG,
+            &[&SIGNERS[0], &SIGNERS[1]],
+            2,
+        )
+        .unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(NEW_MULTISIG, false),
+                AccountMeta::new_readonly(sysvar::rent::id(), false),
+                AccountMeta::new_readonly(SIGNERS[0], false),
+                AccountMeta::new_readonly(SIGNERS[1], false),
+            ],
+            encode(2, &[&[2]]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    assert_eq!(result, Ok(()));
+
+    // And with `InitializeMultisig2`.
+
+    let result = assert_same_outcome(
+        instruction::initialize_multisig2(
+            &spl_token::ID,
+            &NEW_MULTISIG,
+            &[&SIGNERS[0], &SIGNERS[1]],
+            2,
+        )
+        .unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(NEW_MULTISIG, false),
+                AccountMeta::new_readonly(SIGNERS[0], false),
+                AccountMeta::new_readonly(SIGNERS[1], false),
+            ],
+            encode(19, &[&[2]]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    // Then both instructions succeed with the same state.
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn amount_instructions(token_program: Pubkey) {
+    let owner = owner().pubkey();
+    let amount = 100u64.to_le_bytes();
+
+    // When we process each instruction whose data is a discriminator followed by
+    // an amount (`u64`).
+
+    let cases = [
+        (
+            instruction::transfer(&spl_token::ID, &ACCOUNT, &DESTINATION, &owner, &[], 100)
+                .unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new(DESTINATION, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(3, &[&amount]),
+            ),
+        ),
+        (
+            instruction::approve(&spl_token::ID, &ACCOUNT, &DELEGATE, &owner, &[], 100).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new_readonly(DELEGATE, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(4, &[&amount]),
+            ),
+        ),
+        (
+            instruction::mint_to(&spl_token::ID, &MINT, &ACCOUNT, &owner, &[], 100).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(MINT, false),
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(7, &[&amount]),
+            ),
+        ),
+        (
+            instruction::burn(&spl_token::ID, &ACCOUNT, &MINT, &owner, &[], 100).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new(MINT, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(8, &[&amount]),
+            ),
+        ),
+    ];
+
+    // Then each instruction succeeds with the same state.
+
+    for (helper, hand_encoded) in cases {
+        let result = assert_same_outcome(helper, hand_encoded, &token_program).await;
+        assert_eq!(result, Ok(()));
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn checked_instructions(token_program: Pubkey) {
+    let owner = owner().pubkey();
+    let amount = 100u64.to_le_bytes();
+
+    // When we process each instruction whose data is a discriminator followed by
+    // an amount (`u64`) and the mint decimals (`u8`).
+
+    let cases = [
+        (
+            instruction::transfer_checked(
+                &spl_token::ID,
+                &ACCOUNT,
+                &MINT,
+                &DESTINATION,
+                &owner,
+                &[],
+                100,
+                DECIMALS,
+            )
+            .unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new_readonly(MINT, false),
+                    AccountMeta::new(DESTINATION, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(12, &[&amount, &[DECIMALS]]),
+            ),
+        ),
+        (
+            instruction::approve_checked(
+                &spl_token::ID,
+                &ACCOUNT,
+                &MINT,
+                &DELEGATE,
+                &owner,
+                &[],
+                100,
+                DECIMALS,
+            )
+            .unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new_readonly(MINT, false),
+                    AccountMeta::new_readonly(DELEGATE, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(13, &[&amount, &[DECIMALS]]),
+            ),
+        ),
+        (
+            instruction::mint_to_checked(
+                &spl_token::ID,
+                &MINT,
+                &ACCOUNT,
+                &owner,
+                &[],
+                100,
+                DECIMALS,
+            )
+            .unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(MINT, false),
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(14, &[&amount, &[DECIMALS]]),
+            ),
+        ),
+        (
+            instruction::burn_checked(&spl_token::ID, &ACCOUNT, &MINT, &owner, &[], 100, DECIMALS)
+                .unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new(MINT, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(15, &[&amount, &[DECIMALS]]),
+            ),
+        ),
+    ];
+
+    // Then each instruction succeeds with the same state.
+
+    for (helper, hand_encoded) in cases {
+        let result = assert_same_outcome(helper, hand_encoded, &token_program).await;
+        assert_eq!(result, Ok(()));
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn set_authority(token_program: Pubkey) {
+    let owner = owner().pubkey();
+
+    // When we set the close authority of an account.
+    //
+    // Data: discriminator (`6`), authority type (`u8`) and new authority
+    // (`COption<Pubkey>`, with a 1-byte tag).
+
+    let result = assert_same_outcome(
+        instruction::set_authority(
+            &spl_token::ID,
+            &ACCOUNT,
+            Some(&DELEGATE),
+            AuthorityType::CloseAccount,
+            &owner,
+            &[],
+        )
+        .unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(ACCOUNT, false),
+                AccountMeta::new_readonly(owner, true),
+            ],
+            encode(6, &[&[3], &[1], DELEGATE.as_ref()]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    assert_eq!(result, Ok(()));
+
+    // And we clear the freeze authority of the mint.
+
+    let result = assert_same_outcome(
+        instruction::set_authority(
+            &spl_token::ID,
+            &MINT,
+            None,
+            AuthorityType::FreezeAccount,
+            &owner,
+            &[],
+        )
+        .unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(MINT, false),
+                AccountMeta::new_readonly(owner, true),
+            ],
+            encode(6, &[&[1], &[0]]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    // Then both instructions succeed with the same state.
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn no_data_instructions(token_program: Pubkey) {
+    let owner = owner().pubkey();
+
+    // When we process each instruction whose data is only the discriminator.
+
+    let cases = [
+        (
+            instruction::revoke(&spl_token::ID, &ACCOUNT, &owner, &[]).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(5, &[]),
+            ),
+        ),
+        (
+            instruction::close_account(&spl_token::ID, &DESTINATION, &owner, &owner, &[]).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(DESTINATION, false),
+                    AccountMeta::new(owner, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(9, &[]),
+            ),
+        ),
+        (
+            instruction::freeze_account(&spl_token::ID, &ACCOUNT, &MINT, &owner, &[]).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(ACCOUNT, false),
+                    AccountMeta::new_readonly(MINT, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(10, &[]),
+            ),
+        ),
+        (
+            instruction::thaw_account(&spl_token::ID, &FROZEN_ACCOUNT, &MINT, &owner, &[]).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![
+                    AccountMeta::new(FROZEN_ACCOUNT, false),
+                    AccountMeta::new_readonly(MINT, false),
+                    AccountMeta::new_readonly(owner, true),
+                ],
+                encode(11, &[]),
+            ),
+        ),
+        (
+            instruction::sync_native(&spl_token::ID, &NATIVE_ACCOUNT).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![AccountMeta::new(NATIVE_ACCOUNT, false)],
+                encode(17, &[]),
+            ),
+        ),
+        (
+            instruction::get_account_data_size(&spl_token::ID, &MINT).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![AccountMeta::new_readonly(MINT, false)],
+                encode(21, &[]),
+            ),
+        ),
+        (
+            instruction::initialize_immutable_owner(&spl_token::ID, &NEW_ACCOUNT).unwrap(),
+            hand_encoded(
+                &token_program,
+                vec![AccountMeta::new(NEW_ACCOUNT, false)],
+                encode(22, &[]),
+            ),
+        ),
+    ];
+
+    // Then each instruction succeeds with the same state and return data.
+
+    for (helper, hand_encoded) in cases {
+        let result = assert_same_outcome(helper, hand_encoded, &token_program).await;
+        assert_eq!(result, Ok(()));
+    }
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn ui_amount_instructions(token_program: Pubkey) {
+    // When we convert an amount (`u64`) to its UI representation.
+
+    let result = assert_same_outcome(
+        instruction::amount_to_ui_amount(&spl_token::ID, &MINT, 15_000).unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![AccountMeta::new_readonly(MINT, false)],
+            encode(23, &[&15_000u64.to_le_bytes()]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    assert_eq!(result, Ok(()));
+
+    // And a UI amount (UTF-8 string taking the rest of the data) to an amount.
+
+    let result = assert_same_outcome(
+        instruction::ui_amount_to_amount(&spl_token::ID, &MINT, "1.5").unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![AccountMeta::new_readonly(MINT, false)],
+            encode(24, &[b"1.5"]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    // Then both instructions succeed with the same return data.
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test_case::test_case(TOKEN_PROGRAM_ID ; "p-token")]
+#[tokio::test]
+async fn failing_instructions(token_program: Pubkey) {
+    let owner = owner().pubkey();
+
+    // When we transfer more tokens than the account holds.
+
+    let result = assert_same_outcome(
+        instruction::transfer(&spl_token::ID, &ACCOUNT, &DESTINATION, &owner, &[], 5_000).unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(ACCOUNT, false),
+                AccountMeta::new(DESTINATION, false),
+                AccountMeta::new_readonly(owner, true),
+            ],
+            encode(3, &[&5_000u64.to_le_bytes()]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    // Then both instructions fail with `InsufficientFunds`.
+
+    assert_eq!(
+        result,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::InsufficientFunds as u32)
+        ))
+    );
+
+    // When we burn with the wrong number of decimals.
+
+    let result = assert_same_outcome(
+        instruction::burn_checked(&spl_token::ID, &ACCOUNT, &MINT, &owner, &[], 100, 6).unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(ACCOUNT, false),
+                AccountMeta::new(MINT, false),
+                AccountMeta::new_readonly(owner, true),
+            ],
+            encode(15, &[&100u64.to_le_bytes(), &[6]]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    // Then both instructions fail with `MintDecimalsMismatch`.
+
+    assert_eq!(
+        result,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::MintDecimalsMismatch as u32)
+        ))
+    );
+
+    // When we initialize an account that is already initialized.
+
+    let result = assert_same_outcome(
+        instruction::initialize_account3(&spl_token::ID, &ACCOUNT, &MINT, &owner).unwrap(),
+        hand_encoded(
+            &token_program,
+            vec![
+                AccountMeta::new(ACCOUNT, false),
+                AccountMeta::new_readonly(MINT, false),
+            ],
+            encode(18, &[owner.as_ref()]),
+        ),
+        &token_program,
+    )
+    .await;
+
+    // Then both instructions fail with `AlreadyInUse`.
+
+    assert_eq!(
+        result,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(TokenError::AlreadyInUse as u32)
+        ))
+    );
+}
@@ -0,0 +1,13 @@
+#!/usr/bin/env zx
+import 'zx/globals';
+import { cliArguments, workingDirectory } from '../utils.mjs';
+
+// Configure additional arguments here, e.g.:
+// ['--arg1', '--arg2', ...cliArguments()]
+const testArgs = cliArguments();
+
+// The interface crate is an `rlib`, so it is not collected by `getProgramFolders`
+// and its unit tests are not run by `programs:test`. They run on the host target.
+const manifestPath = path.join(workingDirectory, 'interface', 'Cargo.toml');
+
+await $`cargo test --manifest-path ${manifestPath} ${testArgs}`;
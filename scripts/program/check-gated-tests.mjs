@@ -0,0 +1,49 @@
+#!/usr/bin/env zx
+//
+// Checks that the programs integration tests are gated by the `test-sbf`
+// feature.
+//
+// Integration tests run against the SBF binary, so they must only be compiled
+// by `cargo test-sbf`, which enables the feature. Each file in `tests/` (shared
+// modules in subdirectories are not test targets) must start with a crate-level
+// `#![cfg(...)]` attribute that requires `feature = "test-sbf"`. Unit tests in
+// `src` run on the host and are not checked.
+import 'zx/globals';
+import { getProgramFolders, workingDirectory } from '../utils.mjs';
+
+// Matches `#![cfg(feature = "test-sbf")]` and `#![cfg(all(..., feature = "test-sbf", ...))]`.
+const GATE =
+  /^#!\[cfg\((?:feature = "test-sbf"|all\((?:[^()]*, )?feature = "test-sbf"(?:, [^()]*)?\))\)\]$/;
+
+let failed = false;
+
+getProgramFolders().forEach((folder) => {
+  const testsDirectory = path.join(workingDirectory, folder, 'tests');
+
+  if (!fs.existsSync(testsDirectory)) {
+    return;
+  }
+
+  fs.readdirSync(testsDirectory)
+    .filter((file) => file.endsWith('.rs'))
+    .forEach((file) => {
+      // First line that is not blank or a comment.
+      const firstLine = fs
+        .readFileSync(path.join(testsDirectory, file), 'utf8')
+        .split('\n')
+        .map((line) => line.trim())
+        .find((line) => line.length > 0 && !line.startsWith('//'));
+
+      if (!GATE.test(firstLine ?? '')) {
+        echo(
+          chalk.red('[  ERROR  ]'),
+          `'${folder}/tests/${file}' is not gated by the 'test-sbf' feature`
+        );
+        failed = true;
+      }
+    });
+});
+
+if (failed) {
+  await $`exit 1`;
+}
@@ -0,0 +1,29 @@
+#!/usr/bin/env zx
+import 'zx/globals';
+import { cliArguments, popArgument, workingDirectory } from '../utils.mjs';
+
+// Configure additional arguments here, e.g.:
+// ['--arg1', '--arg2', ...cliArguments()]
+const checkArgs = cliArguments();
+
+// When `--latest` is set, the `pinocchio` crates are updated to the latest
+// revision of the dependency source before checking; otherwise, the workspace
+// is checked against the revision pinned in `Cargo.lock`, which is the minimum
+// supported one.
+const latest = popArgument(checkArgs, '--latest');
+const packages = ['pinocchio', 'pinocchio-log', 'pinocchio-pubkey'];
+const lockfile = path.join(workingDirectory, 'Cargo.lock');
+
+if (latest) {
+  const original = fs.readFileSync(lockfile);
+
+  try {
+    await $`cargo update ${packages.flatMap((name) => ['-p', name])}`;
+    await $`cargo check --workspace ${checkArgs}`;
+  } finally {
+    // Restore the pinned revisions.
+    fs.writeFileSync(lockfile, original);
+  }
+} else {
+  await $`cargo check --workspace --locked ${checkArgs}`;
+}
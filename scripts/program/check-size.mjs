@@ -0,0 +1,44 @@
+#!/usr/bin/env zx
+import 'zx/globals';
+import {
+  getCargo,
+  getCargoMetadata,
+  getProgramFolders,
+  workingDirectory,
+} from '../utils.mjs';
+
+// Default maximum size of a program binary (500 KiB). The loader limit is
+// higher, but this keeps the program lean.
+const DEFAULT_SIZE_LIMIT = 500 * 1024;
+
+let failed = false;
+
+getProgramFolders().forEach((folder) => {
+  const name = getCargo(folder).package.name.replace(/-/g, '_');
+  const binary = path.join(workingDirectory, 'target', 'deploy', `${name}.so`);
+
+  if (!fs.existsSync(binary)) {
+    echo(chalk.red('[  ERROR  ]'), `missing program binary '${binary}'`);
+    failed = true;
+    return;
+  }
+
+  const config = getCargoMetadata(folder)?.size ?? {};
+  const limit = config['max-bytes'] ?? DEFAULT_SIZE_LIMIT;
+
+  const data = fs.readFileSync(binary);
+
+  echo(`${folder}: ${data.length} bytes`);
+
+  if (data.length > limit) {
+    echo(
+      chalk.red('[  ERROR  ]'),
+      `'${folder}' binary is ${data.length} bytes (limit ${limit} bytes)`
+    );
+    failed = true;
+  }
+});
+
+if (failed) {
+  await $`exit 1`;
+}